@@ -0,0 +1,436 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::Node;
+
+/// Nodes per chunk. Chunks are never reallocated, so growing the arena doesn't
+/// copy the nodes already in it.
+pub const CHUNK_SIZE: usize = 4096;
+
+/// Slot usage of a [`NodeArena`]
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaStats {
+  pub live: usize,
+  pub free: usize,
+  pub chunks: usize,
+  pub capacity: usize,
+}
+
+impl ArenaStats {
+  /// Share of allocated slots that are free holes, 0 right after compaction
+  pub fn fragmentation(&self) -> f32 {
+    let used = self.live + self.free;
+    if used == 0 {
+      0.0
+    } else {
+      self.free as f32 / used as f32
+    }
+  }
+}
+
+impl fmt::Display for ArenaStats {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} live, {} free ({:.1}% fragmented), {} chunks / {} slots",
+      self.live,
+      self.free,
+      self.fragmentation() * 100.0,
+      self.chunks,
+      self.capacity
+    )
+  }
+}
+
+/// Chunked node storage for editing pools on the CPU. Indices are stable until
+/// [`compact`](NodeArena::compact), child pointers use the same 1-indexed
+/// encoding as the file.
+#[derive(Default)]
+pub struct NodeArena {
+  chunks: Vec<Vec<Node>>,
+  // Per slot, true once freed and until reused
+  freed: Vec<bool>,
+  free_list: Vec<usize>,
+}
+
+impl NodeArena {
+  /// Empty arena, no chunks are allocated until the first node
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Arena holding a copy of `nodes` at the same indices
+  pub fn from_nodes(nodes: &[Node]) -> Self {
+    let mut arena = Self::new();
+    for chunk in nodes.chunks(CHUNK_SIZE) {
+      let mut storage = Vec::with_capacity(CHUNK_SIZE);
+      storage.extend_from_slice(chunk);
+      arena.chunks.push(storage);
+    }
+    arena.freed = vec![false; nodes.len()];
+    arena
+  }
+
+  /// Slots handed out so far, live or free. Valid indices are below this.
+  pub fn len(&self) -> usize {
+    self.freed.len()
+  }
+
+  /// True until the first slot is handed out
+  pub fn is_empty(&self) -> bool {
+    self.freed.is_empty()
+  }
+
+  /// Slots currently holding a node
+  pub fn live(&self) -> usize {
+    self.len() - self.free_list.len()
+  }
+
+  /// Current slot usage, e.g. to decide when to compact
+  pub fn stats(&self) -> ArenaStats {
+    ArenaStats {
+      live: self.live(),
+      free: self.free_list.len(),
+      chunks: self.chunks.len(),
+      capacity: self.chunks.len() * CHUNK_SIZE,
+    }
+  }
+
+  /// Whether `index` holds a node, i.e. is in range and not freed
+  pub fn is_live(&self, index: usize) -> bool {
+    index < self.len() && !self.freed[index]
+  }
+
+  /// The node at `index`, which must be live. Panics if it is out of range.
+  pub fn get(&self, index: usize) -> &Node {
+    debug_assert!(self.is_live(index), "node {} is not live", index);
+    &self.chunks[index / CHUNK_SIZE][index % CHUNK_SIZE]
+  }
+
+  /// Mutable access to the node at `index`, see [`get`](NodeArena::get)
+  pub fn get_mut(&mut self, index: usize) -> &mut Node {
+    debug_assert!(self.is_live(index), "node {} is not live", index);
+    &mut self.chunks[index / CHUNK_SIZE][index % CHUNK_SIZE]
+  }
+
+  /// Stores `node`, reusing a freed slot if there is one
+  pub fn alloc(&mut self, node: Node) -> usize {
+    if let Some(index) = self.free_list.pop() {
+      self.freed[index] = false;
+      *self.get_mut(index) = node;
+      return index;
+    }
+
+    if self.chunks.last().is_none_or(|chunk| chunk.len() == CHUNK_SIZE) {
+      self.chunks.push(Vec::with_capacity(CHUNK_SIZE));
+    }
+    self.chunks.last_mut().unwrap().push(node);
+    self.freed.push(false);
+    self.len() - 1
+  }
+
+  /// Copies `nodes` in, offsetting their child pointers, and returns the
+  /// index of `nodes[0]`, or `None` if `nodes` is empty. Uses fresh slots so
+  /// the copy stays contiguous.
+  ///
+  /// # Errors
+  ///
+  /// Fails without changing the arena if an offset child pointer no longer
+  /// fits the file's `i32` encoding.
+  pub fn append(&mut self, nodes: &[Node]) -> Result<Option<usize>, String> {
+    let offset = i32::try_from(self.len())
+      .map_err(|_| format!("Arena has {} slots, more than child pointers can address", self.len()))?;
+    let mut shifted = Vec::with_capacity(nodes.len());
+    for (index, node) in nodes.iter().enumerate() {
+      let mut node = *node;
+      for (slot, child) in node.children.iter_mut().enumerate() {
+        if *child > 0 {
+          *child = child.checked_add(offset).ok_or_else(|| {
+            format!(
+              "Node {} slot {} points to node {}, past what child pointers can address once appended at {}",
+              index,
+              slot,
+              *child - 1,
+              offset
+            )
+          })?;
+        }
+      }
+      shifted.push(node);
+    }
+
+    let mut first = None;
+    for node in shifted {
+      if self.chunks.last().is_none_or(|chunk| chunk.len() == CHUNK_SIZE) {
+        self.chunks.push(Vec::with_capacity(CHUNK_SIZE));
+      }
+      self.chunks.last_mut().unwrap().push(node);
+      self.freed.push(false);
+      first.get_or_insert(self.len() - 1);
+    }
+    Ok(first)
+  }
+
+  /// Releases a slot. The caller is responsible for nothing pointing at it.
+  pub fn free(&mut self, index: usize) {
+    if !self.is_live(index) {
+      return;
+    }
+    *self.get_mut(index) = Node::default();
+    self.freed[index] = true;
+    self.free_list.push(index);
+  }
+
+  // Live nodes reachable from `root`, in breadth-first order starting at it
+  fn reachable(&self, root: usize) -> Result<Vec<usize>, String> {
+    if !self.is_live(root) {
+      return Err(format!("Node {} is out of range or freed (arena has {} slots)", root, self.len()));
+    }
+
+    let mut seen = vec![false; self.len()];
+    let mut order = vec![root];
+    let mut queue = VecDeque::from([root]);
+    seen[root] = true;
+
+    while let Some(index) = queue.pop_front() {
+      for slot in 0..8 {
+        if let Some(child) = self.get(index).child(slot) {
+          if !self.is_live(child) {
+            return Err(format!(
+              "Node {} slot {} points to node {}, which is out of range or freed",
+              index, slot, child
+            ));
+          }
+          if !seen[child] {
+            seen[child] = true;
+            order.push(child);
+            queue.push_back(child);
+          }
+        }
+      }
+    }
+    Ok(order)
+  }
+
+  /// Frees every live node not reachable from `root`, returning how many
+  pub fn collect(&mut self, root: usize) -> Result<usize, String> {
+    let mut keep = vec![false; self.len()];
+    for index in self.reachable(root)? {
+      keep[index] = true;
+    }
+
+    let mut freed = 0;
+    for (index, keep) in keep.into_iter().enumerate() {
+      if !keep && self.is_live(index) {
+        self.free(index);
+        freed += 1;
+      }
+    }
+    Ok(freed)
+  }
+
+  /// Rebuilds the arena with only the nodes reachable from `root`, packed
+  /// breadth-first with `root` at index 0. Shared (DAG) nodes stay shared.
+  pub fn compact(&mut self, root: usize) -> Result<(), String> {
+    let order = self.reachable(root)?;
+    self.permute(&order)
+  }
+
+  /// Rebuilds the arena with `order[i]` moved to index `i`, remapping child
+  /// pointers. `order` must list every node reachable from its first entry,
+  /// once each; nodes left out are dropped.
+  ///
+  /// # Errors
+  ///
+  /// Fails without changing the arena if a listed node is freed or repeated,
+  /// or points at a node not listed.
+  pub fn permute(&mut self, order: &[usize]) -> Result<(), String> {
+    let mut remap = vec![None; self.len()];
+    for (new_index, &old_index) in order.iter().enumerate() {
+      if !self.is_live(old_index) {
+        return Err(format!("Node {} is out of range or freed (arena has {} slots)", old_index, self.len()));
+      }
+      if remap[old_index].replace(new_index).is_some() {
+        return Err(format!("Node {} is listed more than once", old_index));
+      }
+    }
+
+    let mut nodes = Vec::with_capacity(order.len());
+    for &index in order {
+      let mut node = *self.get(index);
+      for slot in 0..8 {
+        if let Some(child) = node.child(slot) {
+          let new_child = remap.get(child).copied().flatten().ok_or_else(|| {
+            format!("Node {} slot {} points to node {}, which is not in the new order", index, slot, child)
+          })?;
+          node.children[slot] = (new_child + 1) as i32;
+        }
+      }
+      nodes.push(node);
+    }
+
+    *self = Self::from_nodes(&nodes);
+    Ok(())
+  }
+
+  /// Copies the nodes out in index order. Freed slots come out empty, so
+  /// compact first when writing a pool.
+  pub fn to_vec(&self) -> Vec<Node> {
+    self.chunks.iter().flatten().copied().collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn node(children: [i32; 8], yuv: [f32; 3]) -> Node {
+    Node {
+      children,
+      yuv: [yuv[0], yuv[1], yuv[2], 0.0],
+    }
+  }
+
+  // Four nodes, node 3 is shared by nodes 1 and 2
+  fn sample_pool() -> Vec<Node> {
+    vec![
+      node([2, 3, -1, 0, 2, 0, 0, -2], [100.0, 128.0, 128.0]),
+      node([-1, 0, 0, 4, 0, -3, 0, 0], [20.0, 0.0, 0.0]),
+      node([0, -1, 0, 0, 0, 0, 0, 4], [-30.0, 10.0, 0.0]),
+      node([-2, 0, 0, 0, 0, 0, -1, 0], [5.0, 5.0, 5.0]),
+    ]
+  }
+
+  // The tree below `index` with child pointers resolved, equal for pools
+  // that only differ in node order
+  fn decode(nodes: &[Node], index: usize) -> String {
+    let node = &nodes[index];
+    let slots: Vec<String> = (0..8)
+      .map(|slot| match node.child(slot) {
+        Some(child) => decode(nodes, child),
+        None => node.children[slot].to_string(),
+      })
+      .collect();
+    format!("{:?}[{}]", node.yuv, slots.join(","))
+  }
+
+  #[test]
+  fn alloc_reuses_freed_slots() {
+    let mut arena = NodeArena::from_nodes(&sample_pool());
+    arena.free(3);
+    arena.free(3);
+    assert!(!arena.is_live(3));
+    assert_eq!(arena.live(), 3);
+    assert_eq!(arena.stats().free, 1);
+    assert_eq!(arena.stats().fragmentation(), 0.25);
+
+    let leaf = node([-1; 8], [1.0, 2.0, 3.0]);
+    assert_eq!(arena.alloc(leaf), 3);
+    assert_eq!(*arena.get(3), leaf);
+    assert_eq!(arena.alloc(leaf), 4);
+    assert_eq!(arena.live(), 5);
+    assert_eq!(arena.stats().fragmentation(), 0.0);
+  }
+
+  #[test]
+  fn alloc_grows_by_whole_chunks() {
+    let mut arena = NodeArena::new();
+    for i in 0..=CHUNK_SIZE {
+      assert_eq!(arena.alloc(Node::default()), i);
+    }
+    let stats = arena.stats();
+    assert_eq!(stats.chunks, 2);
+    assert_eq!(stats.capacity, 2 * CHUNK_SIZE);
+    assert_eq!(arena.to_vec().len(), CHUNK_SIZE + 1);
+  }
+
+  #[test]
+  fn append_offsets_child_pointers() {
+    let pool = sample_pool();
+    let mut arena = NodeArena::from_nodes(&pool);
+    assert_eq!(arena.append(&pool), Ok(Some(4)));
+    assert_eq!(arena.append(&[]), Ok(None));
+    assert_eq!(arena.get(4).children, [6, 7, -1, 0, 6, 0, 0, -2]);
+    assert_eq!(arena.get(5).children, [-1, 0, 0, 8, 0, -3, 0, 0]);
+    assert_eq!(arena.to_vec()[..4], pool[..]);
+  }
+
+  #[test]
+  fn append_rejects_overflowing_child_pointers() {
+    let mut arena = NodeArena::from_nodes(&sample_pool());
+    let far = node([i32::MAX, 0, 0, 0, 0, 0, 0, 0], [0.0; 3]);
+    assert!(arena.append(&[Node::default(), far]).is_err());
+    assert_eq!(arena.len(), 4);
+    assert_eq!(arena.stats().chunks, 1);
+  }
+
+  #[test]
+  fn collect_frees_unreachable_nodes() {
+    let pool = sample_pool();
+    let mut arena = NodeArena::from_nodes(&pool);
+    arena.append(&pool).unwrap();
+    assert_eq!(arena.collect(0), Ok(4));
+    assert_eq!(arena.live(), 4);
+    assert!((4..8).all(|i| !arena.is_live(i)));
+
+    // Node 3 is shared, so only the root and node 2 go
+    assert_eq!(arena.collect(1), Ok(2));
+    assert!(arena.is_live(1) && arena.is_live(3));
+    assert!(arena.collect(0).is_err());
+  }
+
+  #[test]
+  fn compact_packs_reachable_nodes() {
+    let pool = sample_pool();
+    let mut arena = NodeArena::from_nodes(&pool);
+    arena.append(&pool).unwrap();
+    arena.free(0);
+    arena.compact(4).unwrap();
+    assert_eq!(arena.to_vec(), pool);
+    assert_eq!(arena.stats().free, 0);
+
+    arena.compact(1).unwrap();
+    assert_eq!(arena.to_vec(), [node([-1, 0, 0, 2, 0, -3, 0, 0], [20.0, 0.0, 0.0]), pool[3]]);
+  }
+
+  #[test]
+  fn permute_keeps_the_decoded_pool() {
+    let pool = sample_pool();
+    let mut arena = NodeArena::from_nodes(&pool);
+    arena.permute(&[0, 3, 2, 1]).unwrap();
+    let permuted = arena.to_vec();
+    assert_eq!(permuted[0].children, [4, 3, -1, 0, 4, 0, 0, -2]);
+    assert_eq!(permuted[1], pool[3]);
+    assert_eq!(decode(&permuted, 0), decode(&pool, 0));
+  }
+
+  #[test]
+  fn permute_rejects_incomplete_orders() {
+    let pool = sample_pool();
+    let mut arena = NodeArena::from_nodes(&pool);
+    // Node 1 points at node 3
+    assert!(arena.permute(&[0, 1, 2]).is_err());
+    assert!(arena.permute(&[0, 1, 1, 2, 3]).is_err());
+    assert!(arena.permute(&[0, 1, 2, 3, 4]).is_err());
+    assert_eq!(arena.to_vec(), pool);
+
+    arena.free(3);
+    assert!(arena.permute(&[0, 1, 2, 3]).is_err());
+  }
+}
@@ -0,0 +1,33 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+//! The `.svdag` node pool format written by the Oasis builder and read by the
+//! tools and viewer. Pure Rust, it doesn't link the Oasis C library.
+//!
+//! A pool file is a little-endian `u64` node count followed by that many
+//! [`Node`]s, each eight `i32` child slots then four `f32` YUV components.
+//! Node 0 is the root.
+//!
+//! [`NodeArena`] holds a pool in memory for editing: allocating and freeing
+//! nodes, splicing in other pools and compacting the result for writing.
+
+mod arena;
+mod node;
+mod pool;
+
+pub use arena::{ArenaStats, NodeArena, CHUNK_SIZE};
+pub use node::{validate_children, yuv_to_rgb, InvalidChild, Node};
+pub use pool::{read_nodes, write_nodes, POOL_EXTENSION};
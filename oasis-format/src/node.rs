@@ -0,0 +1,105 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// One octree node, laid out as the C library and the viewer shader expect.
+///
+/// - `children > 0`: 1-indexed pointer to a child node
+/// - `children < 0`: leaf (negated material)
+/// - `children == 0`: empty
+///
+/// Child slot bits are x = 1, y = 2, z = 4. `yuv` is added to the color
+/// accumulated from the parents.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Node {
+  pub children: [i32; 8],
+  pub yuv: [f32; 4],
+}
+
+impl Node {
+  /// Index of the child node in slot `slot`, if that slot points to a node
+  pub fn child(&self, slot: usize) -> Option<usize> {
+    let c = self.children[slot];
+    if c > 0 {
+      Some((c - 1) as usize)
+    } else {
+      None
+    }
+  }
+}
+
+/// Converts an accumulated YUV sum to RGB the same way the viewer shader does
+pub fn yuv_to_rgb(yuv: [f32; 3]) -> [u8; 3] {
+  let y = yuv[0].trunc();
+  let u = yuv[1].trunc() - 128.0;
+  let v = yuv[2].trunc() - 128.0;
+
+  let r = (y + 1.13983 * v) / 255.0;
+  let g = (y - 0.39465 * u - 0.58060 * v) / 255.0;
+  let b = (y + 2.03211 * u) / 255.0;
+
+  [
+    (r.clamp(0.0, 1.0) * 255.0).round() as u8,
+    (g.clamp(0.0, 1.0) * 255.0).round() as u8,
+    (b.clamp(0.0, 1.0) * 255.0).round() as u8,
+  ]
+}
+
+/// First child pointer found outside the pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidChild {
+  pub node: usize,
+  pub slot: usize,
+  pub value: i32,
+  pub count: usize,
+}
+
+impl fmt::Display for InvalidChild {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "node {} child slot {} points to node {} but the pool only has {} nodes",
+      self.node,
+      self.slot,
+      self.value - 1,
+      self.count
+    )
+  }
+}
+
+impl Error for InvalidChild {}
+
+/// Checks that every child pointer lands inside the pool, so a corrupt file
+/// can't send a traversal past the end of it.
+pub fn validate_children(nodes: &[Node]) -> Result<(), InvalidChild> {
+  let count = nodes.len();
+  for (index, node) in nodes.iter().enumerate() {
+    for (slot, &value) in node.children.iter().enumerate() {
+      if value > 0 && (value - 1) as usize >= count {
+        return Err(InvalidChild {
+          node: index,
+          slot,
+          value,
+          count,
+        });
+      }
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,131 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::node::{validate_children, Node};
+
+/// Extension of node pool files
+pub const POOL_EXTENSION: &str = "svdag";
+
+/// Writes `nodes` as a pool file, failing on an empty pool
+pub fn write_nodes<P: AsRef<Path>>(nodes: &[Node], path: P) -> io::Result<()> {
+  if nodes.is_empty() {
+    return Err(io::Error::new(io::ErrorKind::InvalidInput, "Node pool is empty"));
+  }
+
+  let mut file = BufWriter::new(File::create(path)?);
+
+  // Write node count
+  file.write_all(&(nodes.len() as u64).to_le_bytes())?;
+
+  // Write node data field by field so the file is little-endian on any host
+  for node in nodes {
+    for child in &node.children {
+      file.write_all(&child.to_le_bytes())?;
+    }
+    for c in &node.yuv {
+      file.write_all(&c.to_le_bytes())?;
+    }
+  }
+
+  file.flush()
+}
+
+/// Reads a pool file, rejecting child pointers outside the pool with
+/// `InvalidData`
+pub fn read_nodes<P: AsRef<Path>>(path: P) -> io::Result<Vec<Node>> {
+  let mut file = BufReader::new(File::open(path)?);
+
+  let mut count_bytes = [0u8; 8];
+  file.read_exact(&mut count_bytes)?;
+  let count = u64::from_le_bytes(count_bytes) as usize;
+
+  // Don't trust the header for the up-front allocation
+  let mut nodes = Vec::with_capacity(count.min(1 << 20));
+  let mut buf = [0u8; std::mem::size_of::<Node>()];
+  for _ in 0..count {
+    file.read_exact(&mut buf)?;
+
+    let mut node = Node::default();
+    for (i, child) in node.children.iter_mut().enumerate() {
+      *child = i32::from_le_bytes(buf[4 * i..4 * i + 4].try_into().unwrap());
+    }
+    for (i, c) in node.yuv.iter_mut().enumerate() {
+      let o = 32 + 4 * i;
+      *c = f32::from_le_bytes(buf[o..o + 4].try_into().unwrap());
+    }
+    nodes.push(node);
+  }
+
+  validate_children(&nodes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+  Ok(nodes)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("oasis-format-{}-{}", std::process::id(), name))
+  }
+
+  #[test]
+  fn pools_round_trip() {
+    let mut root = Node::default();
+    root.children[0] = 2;
+    root.children[7] = -1;
+    root.yuv = [100.0, 128.0, 128.0, 0.0];
+    let leaf = Node {
+      children: [-3; 8],
+      yuv: [1.5, -2.0, 0.25, 0.0],
+    };
+
+    let path = temp_path("round_trip.svdag");
+    write_nodes(&[root, leaf], &path).unwrap();
+    let read = read_nodes(&path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(read, vec![root, leaf]);
+
+    // Little-endian whatever the host
+    assert_eq!(bytes.len(), 8 + 2 * 48);
+    assert_eq!(bytes[..8], 2u64.to_le_bytes());
+    assert_eq!(bytes[8..12], 2i32.to_le_bytes());
+    assert_eq!(bytes[36..40], (-1i32).to_le_bytes());
+    assert_eq!(bytes[40..44], 100.0f32.to_le_bytes());
+  }
+
+  #[test]
+  fn out_of_range_children_are_rejected() {
+    let mut root = Node::default();
+    root.children[3] = 5;
+
+    let path = temp_path("bad_child.svdag");
+    write_nodes(&[root], &path).unwrap();
+    let error = read_nodes(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("child slot 3 points to node 4"));
+  }
+
+  #[test]
+  fn empty_pools_are_not_written() {
+    assert!(write_nodes(&[], temp_path("empty.svdag")).is_err());
+  }
+}
@@ -0,0 +1,135 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ptr;
+
+extern crate gl;
+use self::gl::types::*;
+
+use nalgebra_glm as glm;
+
+use crate::camera::Camera;
+use crate::shaders::Program;
+
+// Pixels per beam tile side, injected into frag.glsl as BEAM_TILE
+pub const BEAM_TILE: u32 = 8;
+
+// Must match the `uBeam` sampler binding in frag.glsl
+pub const BEAM_TEXTURE_UNIT: GLuint = 1;
+
+// Defines selecting the beam variant of frag.glsl
+pub fn beam_defines() -> Vec<String> {
+  vec!["BEAM_PASS".to_string(), format!("BEAM_TILE {}", BEAM_TILE)]
+}
+
+// World-space width covered by a beam at unit distance. Doubled so a beam also
+// covers the rays of the neighbouring tiles the full pass takes the minimum of.
+pub fn beam_cone(camera: &Camera, height: u32) -> f32 {
+  let pixel = 2.0 * (camera.fov.to_radians() * 0.5).tan() / height as f32;
+  pixel * BEAM_TILE as f32 * 2.0
+}
+
+// Low resolution pre-pass tracing one ray per tile. Each ray stops at the
+// first voxel narrower than its beam, which gives a conservative distance the
+// tile's full resolution rays can start from instead of the camera.
+pub struct BeamPass {
+  fbo: GLuint,
+  texture: GLuint,
+  width: u32,
+  height: u32,
+}
+
+impl BeamPass {
+  // `width` and `height` are the full resolution the beams are traced for
+  pub fn new(width: u32, height: u32) -> Self {
+    let (tiles_x, tiles_y) = (width.div_ceil(BEAM_TILE), height.div_ceil(BEAM_TILE));
+
+    let mut texture: GLuint = 0;
+    let mut fbo: GLuint = 0;
+    unsafe {
+      gl::GenTextures(1, &mut texture);
+      gl::BindTexture(gl::TEXTURE_2D, texture);
+      gl::TexImage2D(
+        gl::TEXTURE_2D,
+        0,
+        gl::R32F as GLint,
+        tiles_x as GLsizei,
+        tiles_y as GLsizei,
+        0,
+        gl::RED,
+        gl::FLOAT,
+        ptr::null(),
+      );
+      // Read with texelFetch only
+      gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST as GLint);
+      gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::NEAREST as GLint);
+      gl::BindTexture(gl::TEXTURE_2D, 0);
+
+      gl::GenFramebuffers(1, &mut fbo);
+      gl::BindFramebuffer(gl::FRAMEBUFFER, fbo);
+      gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, texture, 0);
+      if gl::CheckFramebufferStatus(gl::FRAMEBUFFER) != gl::FRAMEBUFFER_COMPLETE {
+        panic!("ERROR::FRAMEBUFFER::BEAM_TARGET_INCOMPLETE");
+      }
+      gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+    }
+
+    Self {
+      fbo,
+      texture,
+      width: tiles_x,
+      height: tiles_y,
+    }
+  }
+
+  // Traces the beams into the beam texture. Leaves the beam framebuffer bound,
+  // callers bind their own target afterwards. `program` must be the
+  // `beam_defines` variant. `width` and `height` may be below the resolution
+  // the pass was created for, only their tiles are traced.
+  pub fn draw(&self, program: &Program, vao: GLuint, camera: &Camera, width: u32, height: u32, max_depth: u32) {
+    let inv_view_proj = glm::inverse(&camera.get_view_proj_matrix());
+    let tiles_x = width.div_ceil(BEAM_TILE).min(self.width);
+    let tiles_y = height.div_ceil(BEAM_TILE).min(self.height);
+    unsafe {
+      gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+      gl::Viewport(0, 0, tiles_x as GLsizei, tiles_y as GLsizei);
+
+      gl::UseProgram(program.id);
+      gl::Uniform3f(program.location("uPos"), camera.position.x, camera.position.y, camera.position.z);
+      gl::UniformMatrix4fv(program.location("uViewProj"), 1, gl::FALSE, inv_view_proj.as_ptr());
+      gl::Uniform1ui(program.location("uWidth"), width);
+      gl::Uniform1ui(program.location("uHeight"), height);
+      gl::Uniform1ui(program.location("uMaxDepth"), max_depth);
+      gl::Uniform1f(program.location("uBeamCone"), beam_cone(camera, height));
+
+      gl::BindVertexArray(vao);
+      gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+    }
+  }
+
+  pub fn texture(&self) -> GLuint {
+    self.texture
+  }
+}
+
+impl Drop for BeamPass {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteFramebuffers(1, &self.fbo);
+      gl::DeleteTextures(1, &self.texture);
+    }
+  }
+}
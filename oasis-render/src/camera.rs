@@ -0,0 +1,135 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use nalgebra_glm as glm;
+
+/// Narrowest FOV `Camera::zoom` goes to, in degrees
+pub const MIN_FOV: f32 = 10.0;
+/// Widest FOV `Camera::zoom` goes to, in degrees
+pub const MAX_FOV: f32 = 90.0;
+/// FOV of a new camera
+pub const DEFAULT_FOV: f32 = 45.0;
+
+// Rate of the zoom animation, per second
+const FOV_EASE_RATE: f32 = 8.0;
+
+/// Fly camera the renderer traces rays from. Angles are in degrees.
+pub struct Camera {
+  pub position: glm::Vec3,
+  pub front: glm::Vec3,
+  pub up: glm::Vec3,
+  pub right: glm::Vec3,
+  pub world_up: glm::Vec3,
+  pub yaw: f32,
+  pub pitch: f32,
+  pub fov: f32,
+  /// FOV the zoom animation is easing towards
+  pub target_fov: f32,
+  pub aspect_ratio: f32,
+  pub near: f32,
+  pub far: f32,
+}
+
+impl Camera {
+  pub fn new(position: glm::Vec3, aspect_ratio: f32) -> Self {
+    let mut camera = Self {
+      position,
+      front: glm::vec3(0.0, 0.0, -1.0),
+      up: glm::vec3(0.0, 1.0, 0.0),
+      right: glm::vec3(1.0, 0.0, 0.0),
+      world_up: glm::vec3(0.0, 1.0, 0.0),
+      yaw: -90.0,
+      pitch: 0.0,
+      fov: DEFAULT_FOV,
+      target_fov: DEFAULT_FOV,
+      aspect_ratio,
+      near: 0.1,
+      far: 100.0,
+    };
+    camera.update_vectors();
+    camera
+  }
+
+  pub fn get_view_matrix(&self) -> glm::Mat4 {
+    glm::look_at(&self.position, &(self.position + self.front), &self.up)
+  }
+
+  pub fn get_proj_matrix(&self) -> glm::Mat4 {
+    glm::perspective(self.aspect_ratio, self.fov.to_radians(), self.near, self.far)
+  }
+
+  pub fn get_view_proj_matrix(&self) -> glm::Mat4 {
+    self.get_proj_matrix() * self.get_view_matrix()
+  }
+
+  pub fn update_vectors(&mut self) {
+    let yaw_radians = self.yaw.to_radians();
+    let pitch_radians = self.pitch.to_radians();
+
+    let front = glm::vec3(
+      yaw_radians.cos() * pitch_radians.cos(),
+      pitch_radians.sin(),
+      yaw_radians.sin() * pitch_radians.cos(),
+    );
+    self.front = glm::normalize(&front);
+    self.right = glm::normalize(&glm::cross(&self.front, &self.world_up));
+    self.up = glm::normalize(&glm::cross(&self.right, &self.front));
+  }
+
+  pub fn process_mouse_movement(&mut self, x_offset: f32, y_offset: f32, constrain_pitch: bool) {
+    let sensitivity = 0.1;
+    self.yaw += x_offset * sensitivity;
+    self.pitch += y_offset * sensitivity;
+
+    if constrain_pitch {
+      self.pitch = self.pitch.clamp(-89.0, 89.0);
+    }
+
+    self.update_vectors();
+  }
+
+  pub fn zoom(&mut self, delta_degrees: f32) {
+    self.target_fov = (self.target_fov + delta_degrees).clamp(MIN_FOV, MAX_FOV);
+  }
+
+  /// Eases `fov` towards `target_fov`, returns true while still animating
+  pub fn update_fov(&mut self, delta_time: f32) -> bool {
+    let diff = self.target_fov - self.fov;
+    if diff.abs() < 0.01 {
+      self.fov = self.target_fov;
+      return false;
+    }
+    self.fov += diff * (1.0 - (-FOV_EASE_RATE * delta_time).exp());
+    true
+  }
+
+  pub fn process_keyboard(&mut self, direction: CameraMovement, delta_time: f32) {
+    let velocity = 2.5 * delta_time;
+    match direction {
+      CameraMovement::Forward => self.position += self.front * velocity,
+      CameraMovement::Backward => self.position -= self.front * velocity,
+      CameraMovement::Left => self.position -= self.right * velocity,
+      CameraMovement::Right => self.position += self.right * velocity,
+    }
+  }
+}
+
+pub enum CameraMovement {
+  Forward,
+  Backward,
+  Left,
+  Right,
+}
@@ -0,0 +1,91 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+extern crate gl;
+use self::gl::types::*;
+
+/// What the renderer reports as it works. Embedding applications implement
+/// this to route Oasis diagnostics into their own telemetry or editor UI,
+/// every callback defaults to doing nothing.
+pub trait RendererEvents {
+  /// A node pool finished uploading to the GPU, streamed or all at once
+  fn pool_uploaded(&mut self, _nodes: usize, _bytes: usize, _ms: f32) {}
+
+  /// A shader variant was compiled and linked for the given defines
+  fn shader_compiled(&mut self, _defines: &[String], _ms: f32) {}
+
+  /// A frame was drawn, see `FrameTimings`
+  fn frame_rendered(&mut self, _timings: &FrameTimings) {}
+
+  /// `glGetError` reported `code` after one of the renderer's passes. Only
+  /// checked while `Renderer::gl_error_checks` is set.
+  fn gl_error(&mut self, _code: GLenum, _pass: &'static str) {}
+}
+
+/// Timings of one frame. GPU results are read a few frames late to avoid
+/// stalling, so `gpu_ms` belongs to an earlier frame and is None while none
+/// has arrived.
+pub struct FrameTimings {
+  /// Frames rendered so far, this one included
+  pub frame: u64,
+  /// CPU time spent submitting the frame
+  pub cpu_ms: f32,
+  pub gpu_ms: Option<f32>,
+  /// Size actually rendered, below the window size at lowered render scales
+  pub width: u32,
+  pub height: u32,
+}
+
+/// Prints the events worth seeing on stdout, the viewer's default
+pub struct LogEvents;
+
+impl RendererEvents for LogEvents {
+  fn pool_uploaded(&mut self, nodes: usize, bytes: usize, ms: f32) {
+    println!("Uploaded {} nodes ({:.1} MB) in {:.0} ms.", nodes, bytes as f32 / (1024.0 * 1024.0), ms);
+  }
+
+  fn shader_compiled(&mut self, defines: &[String], ms: f32) {
+    println!("Compiled shader variant [{}] in {:.0} ms", defines.join(", "), ms);
+  }
+
+  fn gl_error(&mut self, code: GLenum, pass: &'static str) {
+    eprintln!("GL error {} (0x{:04x}) in the {} pass", error_name(code), code, pass);
+  }
+}
+
+pub fn error_name(code: GLenum) -> &'static str {
+  match code {
+    gl::INVALID_ENUM => "INVALID_ENUM",
+    gl::INVALID_VALUE => "INVALID_VALUE",
+    gl::INVALID_OPERATION => "INVALID_OPERATION",
+    gl::INVALID_FRAMEBUFFER_OPERATION => "INVALID_FRAMEBUFFER_OPERATION",
+    gl::OUT_OF_MEMORY => "OUT_OF_MEMORY",
+    gl::STACK_UNDERFLOW => "STACK_UNDERFLOW",
+    gl::STACK_OVERFLOW => "STACK_OVERFLOW",
+    _ => "unknown",
+  }
+}
+
+/// Reports every error GL has queued since the last check
+pub fn check_gl_errors(events: &mut dyn RendererEvents, pass: &'static str) {
+  loop {
+    let code = unsafe { gl::GetError() };
+    if code == gl::NO_ERROR {
+      break;
+    }
+    events.gl_error(code, pass);
+  }
+}
@@ -32,16 +32,56 @@ const uvec3 AXIS_MASK_VEC = uvec3(AXIS_X_MASK, AXIS_Y_MASK, AXIS_Z_MASK);
 struct DAGNode { int children[8]; vec3 yuv; };
 layout(std430,binding = 3) buffer uuDAG { DAGNode uDAG[]; };
 
+// Beam pre-pass: one ray per BEAM_TILE x BEAM_TILE tile, storing a distance
+// the tile's full resolution rays can safely start from (see beam.rs)
+#ifndef BEAM_TILE
+  #define BEAM_TILE 8
+#endif
+
 layout(binding = 1) uniform sampler2D uBeam;
 
+uniform uint uBeamEnabled; // Full pass: start rays at the beam distance
+uniform float uBeamCone;   // World-space tile width per unit of distance
+
+// Maximum number of lights in a light rig, must match MAX_LIGHTS in lights.rs
+#define MAX_LIGHTS 16
+
+#define LIGHT_POINT 0
+#define LIGHT_SPOT 1
+
+struct Light {
+  vec4 position_range;  // xyz: world position, w: range
+  vec4 color_intensity; // xyz: color, w: intensity
+  vec4 direction_kind;  // xyz: spot direction, w: LIGHT_POINT / LIGHT_SPOT
+  vec4 cone_shadow;     // x: cos(inner), y: cos(outer), z: casts shadows
+};
+
+layout(std140, binding = 2) uniform uuLights {
+  uvec4 uLightCount; // x: number of active lights, 0 = unlit
+  vec4 uAmbient;
+  Light uLights[MAX_LIGHTS];
+};
+
+// Optional per-node attribute channel (see attributes.rs)
+layout(std430, binding = 4) buffer uuAttr { float uAttr[]; };
+
+// With ATTR_HEATMAP defined hits show the attribute channel as a heatmap
+uniform vec2 uAttrRange; // min / max of the channel, for normalization
+
 uniform vec3 uPos;    // Camera world position
 uniform mat4 uViewProj; // Inversed
 
 uniform uint uWidth;  // Viewport width in pixels
 uniform uint uHeight; // Viewport height in pixels
 
-// Output fragment color
-out vec4 oColor;
+uniform uint uMaxDepth; // Deepest level a ray descends to
+uniform uint uShadows;  // 0 skips shadow rays regardless of the light rig
+
+uniform float uTime; // Timeline time in seconds, for time-dependent effects
+
+// Output fragment color and hit distance (1e30 on miss) for the post pass
+layout(location = 0) out vec4 oColor;
+layout(location = 1) out float oDepth;
 
 uint idot(uvec3 a, uvec3 b) {
   return uint(dot(a,b));
@@ -54,6 +94,10 @@ vec2 project_cube(vec3 id, vec3 od, vec3 mn, vec3 mx, out uint incidence_min, ou
   float ts = max(tmn.x, max(tmn.y, tmn.z));
   float te = min(tmx.x, min(tmx.y, tmx.z));
 
+  if (ts == tmn.x) {incidence_min = INCIDENCE_X;}
+  if (ts == tmn.y) {incidence_min = INCIDENCE_Y;}
+  if (ts == tmn.z) {incidence_min = INCIDENCE_Z;}
+
   if (te == tmx.x) {incidence_max = INCIDENCE_X;}
   if (te == tmx.y) {incidence_max = INCIDENCE_Y;}
   if (te == tmx.z) {incidence_max = INCIDENCE_Z;}
@@ -177,6 +221,7 @@ struct ColorItem { vec3 node; } color[MAX_DAG_DEPTH];
 
 bool DAG_RayMarch(vec3 o, vec3 d, 
                   in uint max_depth, 
+                  in float min_dist,
                   in float max_dist, 
                   out float dist, 
                   out uint incidence, 
@@ -208,7 +253,7 @@ bool DAG_RayMarch(vec3 o, vec3 d,
   vec3 id = 1.0 / d;
   vec3 od = -o * id;
 
-  vec2 t = vec2(0, max_dist);
+  vec2 t = vec2(min_dist, max_dist);
   float h = t.y;
 
   uvec3 pos   = ivec3(0);
@@ -263,9 +308,15 @@ bool DAG_RayMarch(vec3 o, vec3 d,
 		  color[ depth ].node = vec3(0);
 
       if (scale <= tc.x * LOD_CUTOFF_CONSTANT 
+#ifdef BEAM_PASS
+        // Voxel is narrower than the beam, neighbouring rays may pass it
+        || float(scale) <= tc.x * uBeamCone * float(MAX_SCALE)
+#endif
         || depth >= max_depth) {
         // voxel is too small
         dist = t.x;
+        vid = (parent << 3) | (dmask ^ idx);
+        incidence = incidence_min;
         return_state = depth >= max_depth ? VOXEL_MARCH_MAX_DEPTH : VOXEL_MARCH_LOD;
         return true;
       }
@@ -282,6 +333,10 @@ bool DAG_RayMarch(vec3 o, vec3 d,
         if (SUBVOXEL_LEAF(subvoxel)) {
           dist = tv.x;
           vid = (parent << 3) | (dmask ^ idx);
+          // Report the face the ray entered the hit voxel through. The last
+          // exit face is the root cube's when no advance step ran, which
+          // would put the normal on the far side
+          incidence = incidence_min;
           return_state = VOXEL_MARCH_HIT;
           material = 0;//SUBVOXEL_MATERIAL(subvoxel);
           //vec3 yuv = uDAG[parent].yuv / vec3(255);
@@ -364,16 +419,117 @@ vec3 GenRay(vec2 uv) {
   return normalize(worldSpace.xyz - uPos);
 }
 
-#ifdef DEBUG
+vec3 HitNormal(vec3 d, uint incidence) {
+  vec3 n = vec3(0.0);
+  n[incidence] = -sign(d[incidence]);
+  return n;
+}
+
+float LightAttenuation(Light light, vec3 p, vec3 l, float light_dist) {
+  float range = max(light.position_range.w, 1e-6);
+  float falloff = clamp(1.0 - light_dist / range, 0.0, 1.0);
+  float atten = falloff * falloff;
+
+  if (uint(light.direction_kind.w) == LIGHT_SPOT) {
+    float cos_angle = dot(-l, light.direction_kind.xyz);
+    atten *= smoothstep(light.cone_shadow.y, light.cone_shadow.x, cos_angle);
+  }
+  return atten;
+}
+
+vec3 ShadeLights(vec3 albedo, vec3 p, vec3 n, uint max_depth) {
+  vec3 result = albedo * uAmbient.rgb;
+
+  for (uint i = 0; i < min(uLightCount.x, uint(MAX_LIGHTS)); i++) {
+    Light light = uLights[i];
+
+    vec3 to_light = light.position_range.xyz - p;
+    float light_dist = length(to_light);
+    vec3 l = to_light / max(light_dist, 1e-6);
+
+    float n_dot_l = max(dot(n, l), 0.0);
+    if (n_dot_l <= 0.0) {
+      continue;
+    }
+
+    float atten = LightAttenuation(light, p, l, light_dist);
+    if (atten <= 0.0) {
+      continue;
+    }
+
+    if (light.cone_shadow.z > 0.5 && uShadows != 0) {
+      // Shadow ray through the DAG, offset along the normal to avoid self hits
+      float sDist;
+      uint sVid, sIncidence, sCode, sIter, sMaterial;
+      vec3 sAttr;
+      bool occluded = DAG_RayMarch(p + n * 1e-4, l,
+                                   max_depth,
+                                   0.0,
+                                   light_dist,
+                                   sDist,
+                                   sIncidence,
+                                   sVid,
+                                   sMaterial,
+                                   sAttr,
+                                   sCode,
+                                   sIter);
+      if (occluded) {
+        continue;
+      }
+    }
+
+    result += albedo * light.color_intensity.rgb * light.color_intensity.w * n_dot_l * atten;
+  }
+  return result;
+}
+
 vec3 Heat(in float x) { return sin(clamp(x, 0.0, 1.0) * 3.0 - vec3(1, 2, 3)) * 0.5 + 0.5; }
-#endif 
+
+// Smallest beam distance around this pixel's tile, pulled back by one beam
+// width so geometry the beam stepped over near its stop point is not skipped
+float BeamStart() {
+  ivec2 tile = ivec2(gl_FragCoord.xy) / BEAM_TILE;
+  // The render scale may leave part of the beam texture unused
+  ivec2 last = (ivec2(uWidth, uHeight) - 1) / BEAM_TILE;
+  float t = 1e30;
+  for (int y = -1; y <= 1; y++) {
+    for (int x = -1; x <= 1; x++) {
+      t = min(t, texelFetch(uBeam, clamp(tile + ivec2(x, y), ivec2(0), last), 0).r);
+    }
+  }
+  return max(t * (1.0 - uBeamCone), 0.0);
+}
 
 void main() {
+  float max_dist = 100.0;
+  uint max_depth = uMaxDepth;
+
+#ifdef BEAM_PASS
+  // Rendered at tile resolution, trace through the tile center
+  vec2 coord = gl_FragCoord.xy * float(BEAM_TILE) / vec2(uWidth, uHeight);
+  vec3 o = uPos, d = GenRay(coord);
+
+  float bDist;
+  uint bVid, bIncidence, bCode, bIter, bMaterial;
+  vec3 bAttr;
+  bool beam_hit = DAG_RayMarch(o, d,
+                               max_depth,
+                               0.0,
+                               max_dist,
+                               bDist,
+                               bIncidence,
+                               bVid,
+                               bMaterial,
+                               bAttr,
+                               bCode,
+                               bIter);
+  // A miss says nothing about the rays next to it, so don't skip anything
+  oColor = vec4(beam_hit ? bDist : 0.0);
+#else
   vec2 coord = gl_FragCoord.xy / vec2(uWidth, uHeight);
 	vec3 o = vec3(uPos.x, uPos.y, uPos.z), d = GenRay(coord);
 
-  float max_dist = 100.0;
-  uint max_depth = 13;
+  float min_dist = uBeamEnabled != 0 ? BeamStart() : 0.0;
 
   float oDist;
   uint oVid;
@@ -384,6 +540,7 @@ void main() {
   vec3 oAttr;
   bool hit = DAG_RayMarch(o, d,
                           max_depth, 
+                          min_dist,
                           max_dist, 
                           oDist, 
                           oIncidence,
@@ -406,14 +563,29 @@ void main() {
     color.g = clamp((Y - 0.39465 * U - 0.58060 * V) / 255.0, 0.0, 1.0);
     color.b = clamp((Y + 2.03211 * U) / 255.0, 0.0, 1.0);
 
+    if (uLightCount.x > 0) {
+      vec3 p = o + d * oDist;
+      color = ShadeLights(color, p, HitNormal(d, oIncidence), max_depth);
+    }
+
+#ifdef ATTR_HEATMAP
+    // oVid packs the hit node index above the child slot
+    float value = uAttr[oVid >> 3];
+    float range = max(uAttrRange.y - uAttrRange.x, 1e-6);
+    color = isnan(value) ? vec3(0.3) : Heat((value - uAttrRange.x) / range);
+#endif
+
     oColor = vec4(color, 1.0);
+    oDepth = oDist;
   }
   else {
     oColor = vec4(0,0,0,1);
+    oDepth = 1e30;
   }
 #ifdef DEBUG
   oColor = vec4(Heat(oIter / 128.0), 1.0);
 #endif
+#endif
 }
 
 
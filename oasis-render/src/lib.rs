@@ -0,0 +1,44 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+//! OpenGL renderer of the viewer, usable from other applications.
+//!
+//! A [`Renderer`] raymarches one node pool into the bound framebuffer from a
+//! [`Camera`], reporting uploads, shader compiles, frame timings and GL
+//! errors to a [`RendererEvents`]. Node pools are uploaded to shader storage
+//! buffers through a [`PoolGpuCache`], which shares one buffer between every
+//! view of a pool. Large pools can be streamed in over several frames with
+//! [`PoolUpload`].
+//!
+//! All functions here issue GL calls and must run with a context current on
+//! the calling thread, and the `gl` function pointers loaded.
+
+mod beam;
+mod camera;
+mod events;
+mod pool_cache;
+mod renderer;
+mod shaders;
+mod timer;
+mod upload;
+
+pub use camera::{Camera, CameraMovement, DEFAULT_FOV, MAX_FOV, MIN_FOV};
+pub use events::{check_gl_errors, error_name, FrameTimings, LogEvents, RendererEvents};
+pub use pool_cache::{GpuPool, PoolGpuCache, PoolId, NODE_SSBO_BINDING};
+pub use renderer::{Renderer, DEFAULT_MAX_DEPTH, FULLSCREEN_VERTEX_SHADER};
+pub use shaders::{compile_shader, link_program, uniform_location, with_defines, Program, ShaderCache};
+pub use timer::TIMER_QUERIES;
+pub use upload::{PoolUpload, DEFAULT_BUDGET_MB};
@@ -0,0 +1,237 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::mem;
+use std::ptr;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+extern crate gl;
+use self::gl::types::*;
+
+use oasis::Node;
+
+use crate::upload::PoolUpload;
+
+/// Must match the `uuDAG` buffer binding in frag.glsl
+pub const NODE_SSBO_BINDING: GLuint = 3;
+
+/// Identifies one loaded pool. Whoever owns the node array takes a fresh id
+/// when it loads or modifies the nodes and hands the same id to every view of
+/// them. Ids are never reused, so a buffer uploaded for old contents can't be
+/// picked up by a pool that happens to land at the same address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PoolId(u64);
+
+impl PoolId {
+  /// Returns an id no earlier call in this process has returned
+  pub fn next() -> Self {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    Self(NEXT.fetch_add(1, Ordering::Relaxed))
+  }
+}
+
+/// A node pool uploaded to an SSBO. The buffer is deleted when the last
+/// `Rc` is dropped, so it must be dropped while its context is current.
+pub struct GpuPool {
+  pub ssbo: GLuint,
+  len: usize,
+  // Leading nodes already copied into `ssbo`, advanced by `PoolUpload`
+  uploaded: Cell<usize>,
+}
+
+impl GpuPool {
+  fn upload(nodes: &[Node]) -> Self {
+    let mut ssbo: GLuint = 0;
+    unsafe {
+      gl::GenBuffers(1, &mut ssbo);
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, ssbo);
+      gl::BufferData(
+        gl::SHADER_STORAGE_BUFFER,
+        mem::size_of_val(nodes) as GLsizeiptr,
+        nodes.as_ptr() as *const _,
+        gl::STATIC_DRAW,
+      );
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, 0);
+    }
+    Self {
+      ssbo,
+      len: nodes.len(),
+      uploaded: Cell::new(nodes.len()),
+    }
+  }
+
+  // Allocates a zeroed buffer for `nodes` to be streamed into by `PoolUpload`.
+  // All-zero nodes have no children, so missing pages render as empty space.
+  fn allocate(nodes: &[Node]) -> Self {
+    let mut ssbo: GLuint = 0;
+    let zero: u32 = 0;
+    unsafe {
+      gl::GenBuffers(1, &mut ssbo);
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, ssbo);
+      gl::BufferData(
+        gl::SHADER_STORAGE_BUFFER,
+        mem::size_of_val(nodes) as GLsizeiptr,
+        ptr::null(),
+        gl::STATIC_DRAW,
+      );
+      gl::ClearBufferData(
+        gl::SHADER_STORAGE_BUFFER,
+        gl::R32UI,
+        gl::RED_INTEGER,
+        gl::UNSIGNED_INT,
+        &zero as *const u32 as *const _,
+      );
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, 0);
+    }
+    Self {
+      ssbo,
+      len: nodes.len(),
+      uploaded: Cell::new(0),
+    }
+  }
+
+  // Copies the nodes a streamed upload hasn't reached yet in one go
+  fn upload_rest(&self, nodes: &[Node]) {
+    let start = self.uploaded.get();
+    let rest = &nodes[start..self.len];
+    unsafe {
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, self.ssbo);
+      gl::BufferSubData(
+        gl::SHADER_STORAGE_BUFFER,
+        (start * mem::size_of::<Node>()) as GLintptr,
+        mem::size_of_val(rest) as GLsizeiptr,
+        rest.as_ptr() as *const _,
+      );
+      gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, 0);
+    }
+    self.uploaded.set(self.len);
+  }
+
+  pub(crate) fn set_uploaded(&self, nodes: usize) {
+    self.uploaded.set(nodes.min(self.len));
+  }
+
+  /// Number of nodes in the pool
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Number of leading nodes copied to the GPU so far
+  pub fn uploaded(&self) -> usize {
+    self.uploaded.get()
+  }
+
+  /// Whether every node has been copied. A pool still streaming in renders
+  /// its missing nodes as empty space.
+  pub fn is_resident(&self) -> bool {
+    self.uploaded.get() >= self.len
+  }
+
+  pub fn bind(&self) {
+    unsafe { gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, NODE_SSBO_BINDING, self.ssbo) }
+  }
+}
+
+impl Drop for GpuPool {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteBuffers(1, &self.ssbo) }
+  }
+}
+
+pub(crate) type PoolMap = HashMap<PoolId, Weak<GpuPool>>;
+
+/// Shares pool SSBOs between renderers. Buffer names are only valid within one
+/// context share group, so keep one cache per share group.
+#[derive(Default)]
+pub struct PoolGpuCache {
+  // Shared with in-flight `PoolUpload`s so an abandoned one can evict its pool
+  pools: Rc<RefCell<PoolMap>>,
+}
+
+impl PoolGpuCache {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  fn lookup(&self, id: PoolId) -> Option<Rc<GpuPool>> {
+    let mut pools = self.pools.borrow_mut();
+    if let Some(pool) = pools.get(&id).and_then(Weak::upgrade) {
+      return Some(pool);
+    }
+
+    // Forget entries whose last user has gone
+    pools.retain(|_, pool| pool.strong_count() > 0);
+    None
+  }
+
+  /// Returns the shared upload of the pool `id`, uploading `nodes` on first
+  /// use. The buffer lives as long as any renderer holds the returned `Rc`.
+  /// If the pool is still streaming in for another view, the rest is uploaded
+  /// now, so the returned pool is always resident.
+  pub fn acquire(&mut self, id: PoolId, nodes: &[Node]) -> Rc<GpuPool> {
+    if let Some(pool) = self.lookup(id) {
+      if !pool.is_resident() {
+        pool.upload_rest(nodes);
+      }
+      return pool;
+    }
+
+    let pool = Rc::new(GpuPool::upload(nodes));
+    self.pools.borrow_mut().insert(id, Rc::downgrade(&pool));
+    pool
+  }
+
+  /// Like `acquire`, but a new upload is streamed in over the following frames
+  /// by stepping the returned `PoolUpload`. A pool that is already resident, or
+  /// still streaming for another view, comes back without one; check
+  /// `GpuPool::is_resident` to tell them apart. Dropping the upload before it
+  /// is done evicts the pool, so the next `acquire` uploads it afresh.
+  pub fn acquire_streamed<'a>(
+    &mut self,
+    id: PoolId,
+    nodes: &'a [Node],
+    budget_mb: usize,
+  ) -> (Rc<GpuPool>, Option<PoolUpload<'a>>) {
+    if let Some(pool) = self.lookup(id) {
+      return (pool, None);
+    }
+
+    let pool = Rc::new(GpuPool::allocate(nodes));
+    self.pools.borrow_mut().insert(id, Rc::downgrade(&pool));
+    let upload = PoolUpload::new(nodes, Rc::clone(&pool), budget_mb, id, Rc::downgrade(&self.pools));
+    (pool, Some(upload))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn pool_ids_are_never_reused() {
+    let ids: Vec<PoolId> = (0..64).map(|_| PoolId::next()).collect();
+    for (i, a) in ids.iter().enumerate() {
+      assert!(ids[i + 1..].iter().all(|b| a != b));
+    }
+  }
+}
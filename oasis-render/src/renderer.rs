@@ -0,0 +1,238 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::mem;
+use std::ptr;
+use std::rc::Rc;
+use std::time::Instant;
+
+extern crate gl;
+use self::gl::types::*;
+
+use nalgebra_glm as glm;
+
+use crate::beam::{self, beam_defines, BeamPass, BEAM_TEXTURE_UNIT};
+use crate::camera::Camera;
+use crate::events::{check_gl_errors, FrameTimings, LogEvents, RendererEvents};
+use crate::pool_cache::GpuPool;
+use crate::shaders::ShaderCache;
+use crate::timer::GpuTimer;
+
+/// Vertex shader of the fullscreen quad, for post passes drawn with
+/// `Renderer::vao`
+pub const FULLSCREEN_VERTEX_SHADER: &str = include_str!("vert.glsl");
+const FRAGMENT_SHADER_SOURCE: &str = include_str!("frag.glsl");
+
+/// Deepest level rays descend to unless the quality controller lowers it
+pub const DEFAULT_MAX_DEPTH: u32 = 13;
+
+/// Raymarches one node pool into the bound framebuffer. Each view owns its
+/// shader variants and VAO, the pool itself is shared through `PoolGpuCache`.
+pub struct Renderer {
+  pool: Rc<GpuPool>,
+  shaders: ShaderCache,
+  vao: GLuint,
+  beam: BeamPass,
+  events: Box<dyn RendererEvents>,
+  timer: GpuTimer,
+  timing: bool,
+  // CPU start of the frame between `begin_frame` and `end_frame`
+  frame_start: Option<Instant>,
+  frames: u64,
+  /// Start full resolution rays from the beam pre-pass distances
+  pub beam_enabled: bool,
+  /// Show traversal iterations per pixel instead of shading
+  pub debug_iterations: bool,
+  /// Traversal depth limit of both passes
+  pub max_depth: u32,
+  /// Trace shadow rays for lights that cast them
+  pub shadows: bool,
+  /// Timeline time in seconds, uTime in the shader
+  pub time: f32,
+  /// Check `glGetError` after each pass and report errors to the events.
+  /// Every check waits for the driver, so it's only on by default in debug
+  /// builds.
+  pub gl_error_checks: bool,
+}
+
+impl Renderer {
+  /// `width` and `height` are the resolution `draw` will render at. Events
+  /// are printed, see `with_events` to receive them instead.
+  pub fn new(pool: Rc<GpuPool>, width: u32, height: u32) -> Self {
+    Self::with_events(pool, width, height, Box::new(LogEvents))
+  }
+
+  pub fn with_events(pool: Rc<GpuPool>, width: u32, height: u32, events: Box<dyn RendererEvents>) -> Self {
+    let mut renderer = Self {
+      pool,
+      shaders: ShaderCache::new(FULLSCREEN_VERTEX_SHADER, FRAGMENT_SHADER_SOURCE),
+      vao: create_fullscreen_quad_vao(),
+      beam: BeamPass::new(width, height),
+      events,
+      timer: GpuTimer::new(),
+      timing: false,
+      frame_start: None,
+      frames: 0,
+      beam_enabled: true,
+      debug_iterations: false,
+      max_depth: DEFAULT_MAX_DEPTH,
+      shadows: true,
+      time: 0.0,
+      gl_error_checks: cfg!(debug_assertions),
+    };
+
+    // Compile the default variants up front so the first frame doesn't stall
+    let defines = renderer.defines(false);
+    renderer.shaders.get(&beam_defines(), renderer.events.as_mut());
+    renderer.shaders.get(&defines, renderer.events.as_mut());
+    renderer
+  }
+
+  /// Receiver of this renderer's events, also for work done outside it on its
+  /// behalf such as pool uploads
+  pub fn events(&mut self) -> &mut dyn RendererEvents {
+    self.events.as_mut()
+  }
+
+  /// Call around everything the frame draws, post-processing included.
+  /// `end_frame` reports the frame and returns its GPU time in ms once the
+  /// result of an earlier frame has arrived.
+  pub fn begin_frame(&mut self) {
+    self.timing = self.timer.begin();
+    self.frame_start = Some(Instant::now());
+  }
+
+  pub fn end_frame(&mut self, width: u32, height: u32) -> Option<f32> {
+    if self.timing {
+      self.timer.end();
+      self.timing = false;
+    }
+    let cpu_ms = self.frame_start.take().map_or(0.0, |start| start.elapsed().as_secs_f32() * 1000.0);
+    let gpu_ms = self.timer.poll();
+    self.frames += 1;
+    self.check_gl_errors("frame");
+    self.events.frame_rendered(&FrameTimings {
+      frame: self.frames,
+      cpu_ms,
+      gpu_ms,
+      width,
+      height,
+    });
+    gpu_ms
+  }
+
+  /// Fullscreen quad, also used by the post pass
+  pub fn vao(&self) -> GLuint {
+    self.vao
+  }
+
+  // Defines of the full resolution variant for the current modes
+  fn defines(&self, heatmap: bool) -> Vec<String> {
+    let mut defines = vec![format!("BEAM_TILE {}", beam::BEAM_TILE)];
+    if heatmap {
+      defines.push("ATTR_HEATMAP".to_string());
+    }
+    if self.debug_iterations {
+      defines.push("DEBUG".to_string());
+    }
+    defines
+  }
+
+  /// Runs the beam pre-pass, call before binding the target `draw` renders to.
+  /// Both passes take the size actually rendered, which is below the size the
+  /// renderer was created for when the render scale is lowered.
+  pub fn draw_beam(&mut self, camera: &Camera, width: u32, height: u32) {
+    if self.beam_enabled {
+      self.pool.bind();
+      let program = self.shaders.get(&beam_defines(), self.events.as_mut());
+      self.beam.draw(program, self.vao, camera, width, height, self.max_depth);
+      self.check_gl_errors("beam");
+    }
+  }
+
+  /// `heatmap` is the attribute range to normalize by, or None to shade normally.
+  /// The light UBO and attribute SSBO are expected to be bound already.
+  pub fn draw(&mut self, camera: &Camera, width: u32, height: u32, heatmap: Option<(f32, f32)>) {
+    let inv_view_proj = glm::inverse(&camera.get_view_proj_matrix());
+    let defines = self.defines(heatmap.is_some());
+    let program = self.shaders.get(&defines, self.events.as_mut());
+    unsafe {
+      gl::ClearColor(0.2, 0.3, 0.3, 1.0);
+      gl::Clear(gl::COLOR_BUFFER_BIT);
+
+      gl::UseProgram(program.id);
+      gl::Uniform3f(program.location("uPos"), camera.position.x, camera.position.y, camera.position.z);
+      gl::UniformMatrix4fv(program.location("uViewProj"), 1, gl::FALSE, inv_view_proj.as_ptr());
+      gl::Uniform1ui(program.location("uWidth"), width);
+      gl::Uniform1ui(program.location("uHeight"), height);
+      gl::Uniform1ui(program.location("uMaxDepth"), self.max_depth);
+      gl::Uniform1ui(program.location("uShadows"), self.shadows as GLuint);
+      gl::Uniform1f(program.location("uTime"), self.time);
+      if let Some((min, max)) = heatmap {
+        gl::Uniform2f(program.location("uAttrRange"), min, max);
+      }
+      gl::Uniform1ui(program.location("uBeamEnabled"), self.beam_enabled as GLuint);
+      gl::Uniform1f(program.location("uBeamCone"), beam::beam_cone(camera, height));
+      gl::BindTextureUnit(BEAM_TEXTURE_UNIT, self.beam.texture());
+      self.pool.bind();
+
+      gl::BindVertexArray(self.vao);
+      gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+    }
+    self.check_gl_errors("raymarch");
+  }
+
+  fn check_gl_errors(&mut self, pass: &'static str) {
+    if self.gl_error_checks {
+      check_gl_errors(self.events.as_mut(), pass);
+    }
+  }
+}
+
+impl Drop for Renderer {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteVertexArrays(1, &self.vao) }
+  }
+}
+
+fn create_fullscreen_quad_vao() -> GLuint {
+  let vertices: [f32; 12] = [
+    -1.0, -1.0, 0.0,
+     1.0, -1.0, 0.0,
+    -1.0,  1.0, 0.0,
+     1.0,  1.0, 0.0,
+  ];
+  let (mut vbo, mut vao) = (0, 0);
+  unsafe {
+    gl::GenVertexArrays(1, &mut vao);
+    gl::GenBuffers(1, &mut vbo);
+    gl::BindVertexArray(vao);
+
+    gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
+    gl::BufferData(gl::ARRAY_BUFFER,
+      mem::size_of_val(&vertices) as isize,
+      vertices.as_ptr() as *const _,
+      gl::STATIC_DRAW
+    );
+
+    gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 0, ptr::null());
+    gl::EnableVertexAttribArray(0);
+
+    gl::BindBuffer(gl::ARRAY_BUFFER, 0);
+    gl::BindVertexArray(0);
+  }
+  vao
+}
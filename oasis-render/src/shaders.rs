@@ -0,0 +1,175 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::ptr;
+use std::str;
+use std::time::Instant;
+
+extern crate gl;
+use self::gl::types::*;
+
+use crate::events::RendererEvents;
+
+/// Compiles one stage, panicking with the info log if it fails
+pub fn compile_shader(src: &str, shader_type: GLenum) -> GLuint {
+  let shader = unsafe { gl::CreateShader(shader_type) };
+  let c_str = CString::new(src).unwrap();
+  unsafe {
+    gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
+    gl::CompileShader(shader);
+
+    let mut success = gl::FALSE as GLint;
+    gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
+    if success != gl::TRUE as GLint {
+      let mut info_log = vec![0; 512];
+      gl::GetShaderInfoLog(shader, 512, ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
+      panic!(
+        "ERROR::SHADER::{:?}::COMPILATION_FAILED\n{}",
+        shader_type,
+        str::from_utf8(&info_log).unwrap()
+      );
+    }
+  }
+  shader
+}
+
+/// Links a vertex and fragment shader, deleting both. Panics with the info
+/// log if linking fails.
+pub fn link_program(vs: GLuint, fs: GLuint) -> GLuint {
+  let program = unsafe { gl::CreateProgram() };
+  unsafe {
+    gl::AttachShader(program, vs);
+    gl::AttachShader(program, fs);
+    gl::LinkProgram(program);
+
+    let mut success = gl::FALSE as GLint;
+    gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
+    if success != gl::TRUE as GLint {
+      let mut info_log = vec![0; 512];
+      gl::GetProgramInfoLog(program, 512, ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
+      panic!("ERROR::PROGRAM::LINKING_FAILED\n{}", str::from_utf8(&info_log).unwrap());
+    }
+
+    gl::DeleteShader(vs);
+    gl::DeleteShader(fs);
+  }
+  program
+}
+
+pub fn uniform_location(program: GLuint, name: &str) -> GLint {
+  let c_name = CString::new(name).unwrap();
+  unsafe { gl::GetUniformLocation(program, c_name.as_ptr()) }
+}
+
+/// Inserts `#define`s right after the `#version` line, which must come first
+pub fn with_defines(src: &str, defines: &[String]) -> String {
+  let mut out = String::with_capacity(src.len() + defines.len() * 32);
+  let mut injected = false;
+  for line in src.lines() {
+    out.push_str(line);
+    out.push('\n');
+    if !injected && line.trim_start().starts_with("#version") {
+      for define in defines {
+        out.push_str("#define ");
+        out.push_str(define);
+        out.push('\n');
+      }
+      injected = true;
+    }
+  }
+  out
+}
+
+/// A linked program with the locations of its active uniforms
+pub struct Program {
+  pub id: GLuint,
+  locations: HashMap<String, GLint>,
+}
+
+impl Program {
+  fn new(id: GLuint) -> Self {
+    let mut locations = HashMap::new();
+    unsafe {
+      let mut count: GLint = 0;
+      gl::GetProgramiv(id, gl::ACTIVE_UNIFORMS, &mut count);
+      let mut name = [0 as GLchar; 256];
+      for i in 0..count as GLuint {
+        let (mut length, mut size, mut kind) = (0, 0, 0);
+        gl::GetActiveUniform(id, i, name.len() as GLsizei, &mut length, &mut size, &mut kind, name.as_mut_ptr());
+        let location = gl::GetUniformLocation(id, name.as_ptr());
+        // Uniforms inside blocks have no location
+        if location >= 0 {
+          let name = CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned();
+          locations.insert(name, location);
+        }
+      }
+    }
+    Self { id, locations }
+  }
+
+  /// -1 for uniforms this variant compiled out, which GL silently ignores
+  pub fn location(&self, name: &str) -> GLint {
+    self.locations.get(name).copied().unwrap_or(-1)
+  }
+}
+
+/// Compiles variants of one shader source with injected `#define`s on first
+/// use and keeps them, so render modes are picked by switching programs
+/// instead of branching in the shader.
+pub struct ShaderCache {
+  vertex: &'static str,
+  fragment: &'static str,
+  // Keyed by the sorted define list, so the order callers pass them in doesn't matter
+  programs: HashMap<Vec<String>, Program>,
+}
+
+impl ShaderCache {
+  pub fn new(vertex: &'static str, fragment: &'static str) -> Self {
+    Self {
+      vertex,
+      fragment,
+      programs: HashMap::new(),
+    }
+  }
+
+  /// Each define is `NAME` or `NAME value`. Compiling a new variant is
+  /// reported to `events`.
+  pub fn get(&mut self, defines: &[String], events: &mut dyn RendererEvents) -> &Program {
+    let mut key = defines.to_vec();
+    key.sort();
+    key.dedup();
+
+    let (vertex, fragment) = (self.vertex, self.fragment);
+    self.programs.entry(key).or_insert_with_key(|key| {
+      let start = Instant::now();
+      let vs = compile_shader(&with_defines(vertex, key), gl::VERTEX_SHADER);
+      let fs = compile_shader(&with_defines(fragment, key), gl::FRAGMENT_SHADER);
+      let program = Program::new(link_program(vs, fs));
+      events.shader_compiled(key, start.elapsed().as_secs_f32() * 1000.0);
+      program
+    })
+  }
+}
+
+impl Drop for ShaderCache {
+  fn drop(&mut self) {
+    for program in self.programs.values() {
+      unsafe { gl::DeleteProgram(program.id) }
+    }
+  }
+}
@@ -0,0 +1,81 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+extern crate gl;
+use self::gl::types::*;
+
+/// Queries in flight, results are read a few frames late to avoid stalling
+pub const TIMER_QUERIES: usize = 3;
+
+/// GPU time of each frame through a ring of TIME_ELAPSED queries. Queries of
+/// one target can't nest, so keep a single timer around the frame.
+pub struct GpuTimer {
+  queries: [GLuint; TIMER_QUERIES],
+  pending: [bool; TIMER_QUERIES],
+  next: usize,
+}
+
+impl GpuTimer {
+  pub fn new() -> Self {
+    let mut queries = [0; TIMER_QUERIES];
+    unsafe { gl::GenQueries(TIMER_QUERIES as GLsizei, queries.as_mut_ptr()) }
+    Self {
+      queries,
+      pending: [false; TIMER_QUERIES],
+      next: 0,
+    }
+  }
+
+  /// Starts timing a frame, returns false while every query is still pending
+  pub fn begin(&mut self) -> bool {
+    if self.pending[self.next] {
+      return false;
+    }
+    unsafe { gl::BeginQuery(gl::TIME_ELAPSED, self.queries[self.next]) }
+    true
+  }
+
+  pub fn end(&mut self) {
+    unsafe { gl::EndQuery(gl::TIME_ELAPSED) }
+    self.pending[self.next] = true;
+    self.next = (self.next + 1) % TIMER_QUERIES;
+  }
+
+  /// Milliseconds of the oldest frame whose result has arrived
+  pub fn poll(&mut self) -> Option<f32> {
+    let oldest = self.next;
+    if !self.pending[oldest] {
+      return None;
+    }
+    let mut available: GLint = 0;
+    let mut elapsed: GLuint64 = 0;
+    unsafe {
+      gl::GetQueryObjectiv(self.queries[oldest], gl::QUERY_RESULT_AVAILABLE, &mut available);
+      if available == 0 {
+        return None;
+      }
+      gl::GetQueryObjectui64v(self.queries[oldest], gl::QUERY_RESULT, &mut elapsed);
+    }
+    self.pending[oldest] = false;
+    Some(elapsed as f32 / 1.0e6)
+  }
+}
+
+impl Drop for GpuTimer {
+  fn drop(&mut self) {
+    unsafe { gl::DeleteQueries(TIMER_QUERIES as GLsizei, self.queries.as_ptr()) }
+  }
+}
@@ -0,0 +1,189 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::mem;
+use std::ptr;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+extern crate gl;
+use self::gl::types::*;
+
+use oasis::Node;
+
+use crate::pool_cache::{GpuPool, PoolId, PoolMap};
+
+// Nodes copied per staging page (3 MiB at 48 bytes per node)
+const PAGE_NODES: usize = 64 * 1024;
+
+// Pages in flight. While the GPU copies out of one, the next is being filled.
+const STAGING_PAGES: usize = 3;
+
+/// Per-frame upload budget used when the caller has no preference. The copy
+/// into the staging pages is a plain memcpy on the calling (render) thread, so
+/// the budget is paid in frame time: at a few GB/s 8 MB costs roughly 1-3 ms.
+/// Raise it for faster loading if the frame rate can afford it.
+pub const DEFAULT_BUDGET_MB: usize = 8;
+
+struct StagingPage {
+  buffer: GLuint,
+  // Persistently mapped, stays valid until the buffer is deleted
+  mapped: *mut u8,
+  // Signalled once the GPU has finished copying out of this page
+  fence: GLsync,
+}
+
+/// Streams a pool into its SSBO a few pages per frame through persistently
+/// mapped staging buffers, so a multi-GB pool never stalls the render loop on
+/// one huge glBufferData. Nodes not uploaded yet read as zero, i.e. empty, so
+/// the pool fills in progressively while it can already be explored.
+pub struct PoolUpload<'a> {
+  nodes: &'a [Node],
+  pool: Rc<GpuPool>,
+  pages: [StagingPage; STAGING_PAGES],
+  next_page: usize,
+  budget_bytes: usize,
+  id: PoolId,
+  cache: Weak<RefCell<PoolMap>>,
+}
+
+impl<'a> PoolUpload<'a> {
+  // `pool` must have been allocated for `nodes`. `budget_mb` caps how much is
+  // copied per `step`, at least one page is copied regardless.
+  pub(crate) fn new(
+    nodes: &'a [Node],
+    pool: Rc<GpuPool>,
+    budget_mb: usize,
+    id: PoolId,
+    cache: Weak<RefCell<PoolMap>>,
+  ) -> Self {
+    let page_bytes = (PAGE_NODES * mem::size_of::<Node>()) as GLsizeiptr;
+    let flags = gl::MAP_WRITE_BIT | gl::MAP_PERSISTENT_BIT | gl::MAP_COHERENT_BIT;
+
+    let pages = [(); STAGING_PAGES].map(|_| {
+      let mut buffer: GLuint = 0;
+      let mapped = unsafe {
+        gl::GenBuffers(1, &mut buffer);
+        gl::BindBuffer(gl::COPY_READ_BUFFER, buffer);
+        gl::BufferStorage(gl::COPY_READ_BUFFER, page_bytes, ptr::null(), flags);
+        let mapped = gl::MapBufferRange(gl::COPY_READ_BUFFER, 0, page_bytes, flags) as *mut u8;
+        gl::BindBuffer(gl::COPY_READ_BUFFER, 0);
+        mapped
+      };
+      if mapped.is_null() {
+        panic!("ERROR::UPLOAD::STAGING_MAP_FAILED");
+      }
+      StagingPage {
+        buffer,
+        mapped,
+        fence: ptr::null(),
+      }
+    });
+
+    Self {
+      nodes,
+      pool,
+      pages,
+      next_page: 0,
+      budget_bytes: budget_mb * 1024 * 1024,
+      id,
+      cache,
+    }
+  }
+
+  pub fn is_done(&self) -> bool {
+    self.pool.is_resident()
+  }
+
+  /// Fraction of nodes uploaded, in [0, 1]
+  pub fn progress(&self) -> f32 {
+    if self.nodes.is_empty() {
+      1.0
+    } else {
+      self.pool.uploaded() as f32 / self.nodes.len() as f32
+    }
+  }
+
+  /// Queues copies for up to the per-frame budget without waiting on the GPU.
+  /// Stops early when the next staging page is still being copied from.
+  pub fn step(&mut self) {
+    let node_size = mem::size_of::<Node>();
+    let mut copied = 0;
+
+    while !self.is_done() && (copied == 0 || copied < self.budget_bytes) {
+      let page = &mut self.pages[self.next_page];
+      if !page.fence.is_null() {
+        let status = unsafe { gl::ClientWaitSync(page.fence, 0, 0) };
+        if status != gl::ALREADY_SIGNALED && status != gl::CONDITION_SATISFIED {
+          break;
+        }
+        unsafe { gl::DeleteSync(page.fence) };
+        page.fence = ptr::null();
+      }
+
+      let uploaded = self.pool.uploaded();
+      let count = PAGE_NODES.min(self.nodes.len() - uploaded);
+      let bytes = count * node_size;
+      unsafe {
+        ptr::copy_nonoverlapping(self.nodes[uploaded..].as_ptr() as *const u8, page.mapped, bytes);
+
+        gl::BindBuffer(gl::COPY_READ_BUFFER, page.buffer);
+        gl::BindBuffer(gl::COPY_WRITE_BUFFER, self.pool.ssbo);
+        gl::CopyBufferSubData(
+          gl::COPY_READ_BUFFER,
+          gl::COPY_WRITE_BUFFER,
+          0,
+          (uploaded * node_size) as GLintptr,
+          bytes as GLsizeiptr,
+        );
+        gl::BindBuffer(gl::COPY_READ_BUFFER, 0);
+        gl::BindBuffer(gl::COPY_WRITE_BUFFER, 0);
+        page.fence = gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+      }
+
+      self.pool.set_uploaded(uploaded + count);
+      copied += bytes;
+      self.next_page = (self.next_page + 1) % STAGING_PAGES;
+    }
+  }
+}
+
+impl Drop for PoolUpload<'_> {
+  fn drop(&mut self) {
+    // The unfilled nodes would stay empty for good, so make sure nobody else
+    // picks up this pool from the cache as if it were complete
+    if !self.is_done() {
+      if let Some(cache) = self.cache.upgrade() {
+        let mut pools = cache.borrow_mut();
+        if pools.get(&self.id).is_some_and(|pool| ptr::eq(pool.as_ptr(), Rc::as_ptr(&self.pool))) {
+          pools.remove(&self.id);
+        }
+      }
+    }
+
+    for page in &self.pages {
+      unsafe {
+        if !page.fence.is_null() {
+          gl::DeleteSync(page.fence);
+        }
+        gl::BindBuffer(gl::COPY_READ_BUFFER, page.buffer);
+        gl::UnmapBuffer(gl::COPY_READ_BUFFER);
+        gl::BindBuffer(gl::COPY_READ_BUFFER, 0);
+        gl::DeleteBuffers(1, &page.buffer);
+      }
+    }
+  }
+}
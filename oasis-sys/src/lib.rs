@@ -14,7 +14,11 @@
  * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
  */
  
-// src/lib.rs
+//! Raw FFI bindings to the Oasis C API, generated from `oasis.h` by bindgen.
+//!
+//! Everything here is `unsafe` and mirrors the header one to one. Use the
+//! `oasis` crate for the safe wrapper.
+
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
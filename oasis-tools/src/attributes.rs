@@ -0,0 +1,405 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use oasis_format::Node;
+
+use crate::scene_loader::{Scene, AABB};
+
+// Sidecar file next to the .svdag holding per-node attribute channels:
+//   magic "OATR", u32 version, u64 node count, u32 channel count,
+//   per channel: u32 name length + UTF-8 name,
+//   then `node count` f32 values per channel (NaN where no triangle landed).
+pub const ATTRIBUTE_MAGIC: &[u8; 4] = b"OATR";
+pub const ATTRIBUTE_VERSION: u32 = 1;
+pub const ATTRIBUTE_EXTENSION: &str = "svattr";
+
+// Float channels with one value per scene triangle
+#[derive(Debug)]
+pub struct TriangleAttributes {
+  pub names: Vec<String>,
+  pub values: Vec<Vec<f32>>,
+}
+
+impl TriangleAttributes {
+  pub fn is_empty(&self) -> bool {
+    self.names.is_empty()
+  }
+
+  pub fn append(&mut self, mut other: TriangleAttributes) {
+    self.names.append(&mut other.names);
+    self.values.append(&mut other.values);
+  }
+}
+
+// Reads a CSV with a `triangle` column followed by one column per channel.
+// Triangles are numbered in load order, after triangulation.
+pub fn load_csv<P: AsRef<Path>>(path: P, triangle_count: usize) -> Result<TriangleAttributes, String> {
+  let path = path.as_ref();
+  let text = fs::read_to_string(path)
+    .map_err(|e| format!("Failed to read attributes '{}': {e}", path.display()))?;
+
+  let mut lines = text.lines().enumerate().filter(|(_, l)| !l.trim().is_empty());
+  let (_, header) = lines
+    .next()
+    .ok_or_else(|| format!("Attributes file '{}' is empty", path.display()))?;
+
+  let mut columns = header.split(',').map(|c| c.trim());
+  if columns.next() != Some("triangle") {
+    return Err(format!(
+      "Attributes file '{}' must start with a 'triangle' column",
+      path.display()
+    ));
+  }
+  let names: Vec<String> = columns.map(String::from).collect();
+  if names.is_empty() {
+    return Err(format!("Attributes file '{}' has no channels", path.display()));
+  }
+
+  let mut values = vec![vec![f32::NAN; triangle_count]; names.len()];
+  for (line_no, line) in lines {
+    let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+    if fields.len() != names.len() + 1 {
+      return Err(format!(
+        "{}:{}: expected {} fields, found {}",
+        path.display(),
+        line_no + 1,
+        names.len() + 1,
+        fields.len()
+      ));
+    }
+
+    let tri: usize = fields[0]
+      .parse()
+      .map_err(|_| format!("{}:{}: invalid triangle index '{}'", path.display(), line_no + 1, fields[0]))?;
+    if tri >= triangle_count {
+      return Err(format!(
+        "{}:{}: triangle {} out of range (scene has {} triangles)",
+        path.display(),
+        line_no + 1,
+        tri,
+        triangle_count
+      ));
+    }
+
+    for (channel, field) in fields[1..].iter().enumerate() {
+      values[channel][tri] = field
+        .parse()
+        .map_err(|_| format!("{}:{}: invalid value '{}'", path.display(), line_no + 1, field))?;
+    }
+  }
+
+  Ok(TriangleAttributes { names, values })
+}
+
+// Reads a JSON object with one array per channel, holding a value (or null
+// where there is none) for every triangle in load order:
+//   { "temperature": [20.5, 21.0, null, ...], "stress": [...] }
+// Channels come out sorted by name.
+pub fn load_json<P: AsRef<Path>>(path: P, triangle_count: usize) -> Result<TriangleAttributes, String> {
+  let path = path.as_ref();
+  let text = fs::read_to_string(path)
+    .map_err(|e| format!("Failed to read attributes '{}': {e}", path.display()))?;
+  let json: serde_json::Value =
+    serde_json::from_str(&text).map_err(|e| format!("{}: invalid JSON: {e}", path.display()))?;
+  let channels = json
+    .as_object()
+    .ok_or_else(|| format!("Attributes file '{}' must be an object of channel arrays", path.display()))?;
+  if channels.is_empty() {
+    return Err(format!("Attributes file '{}' has no channels", path.display()));
+  }
+
+  let mut attributes = TriangleAttributes {
+    names: Vec::with_capacity(channels.len()),
+    values: Vec::with_capacity(channels.len()),
+  };
+  for (name, channel) in channels {
+    let channel = channel
+      .as_array()
+      .ok_or_else(|| format!("{}: channel '{}' must be an array", path.display(), name))?;
+    if channel.len() != triangle_count {
+      return Err(format!(
+        "{}: channel '{}' has {} values, the scene has {} triangles",
+        path.display(),
+        name,
+        channel.len(),
+        triangle_count
+      ));
+    }
+
+    let values = channel
+      .iter()
+      .enumerate()
+      .map(|(tri, value)| match value {
+        serde_json::Value::Null => Ok(f32::NAN),
+        value => value.as_f64().map(|v| v as f32).ok_or_else(|| {
+          format!("{}: channel '{}' triangle {}: invalid value {}", path.display(), name, tri, value)
+        }),
+      })
+      .collect::<Result<_, _>>()?;
+    attributes.names.push(name.clone());
+    attributes.values.push(values);
+  }
+  Ok(attributes)
+}
+
+// Picks the loader by extension, `.json` or CSV otherwise
+pub fn load<P: AsRef<Path>>(path: P, triangle_count: usize) -> Result<TriangleAttributes, String> {
+  let path = path.as_ref();
+  if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+    load_json(path, triangle_count)
+  } else {
+    load_csv(path, triangle_count)
+  }
+}
+
+// Averages the scene's vertex colors per triangle into three channels
+pub fn from_vertex_colors(scene: &Scene) -> Option<TriangleAttributes> {
+  if scene.vertex_colors.is_empty() {
+    return None;
+  }
+
+  let mut values: Vec<Vec<f32>> = (0..3).map(|_| Vec::with_capacity(scene.triangles_indexed.len())).collect();
+  for tri in &scene.triangles_indexed {
+    for (k, channel) in values.iter_mut().enumerate() {
+      let sum: f32 = tri.v_idx.iter().map(|&v| scene.vertex_colors[v][k]).sum();
+      channel.push(sum / 3.0);
+    }
+  }
+
+  Some(TriangleAttributes {
+    names: vec![
+      String::from("vertex_r"),
+      String::from("vertex_g"),
+      String::from("vertex_b"),
+    ],
+    values,
+  })
+}
+
+// Float channels with one value per pool node
+pub struct NodeAttributes {
+  pub names: Vec<String>,
+  pub values: Vec<Vec<f32>>,
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+  a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+// Separating axis test between a triangle and the cube centered on `center`
+// with half edge `half`. Touching counts as overlapping, so triangles lying
+// on a cell face reach the cells on both sides.
+fn triangle_overlaps_cube(tri: &[[f32; 3]; 3], center: [f32; 3], half: f32) -> bool {
+  let v = tri.map(|p| sub(p, center));
+  let edges = [sub(v[1], v[0]), sub(v[2], v[1]), sub(v[0], v[2])];
+  let separated = |axis: [f32; 3]| {
+    let p = v.map(|p| dot(p, axis));
+    let r = half * (axis[0].abs() + axis[1].abs() + axis[2].abs());
+    p[0].min(p[1]).min(p[2]) > r || p[0].max(p[1]).max(p[2]) < -r
+  };
+
+  let units = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+  if units.iter().any(|&unit| separated(unit)) || separated(cross(edges[0], edges[1])) {
+    return false;
+  }
+  !edges.iter().any(|&edge| units.iter().any(|&unit| separated(cross(edge, unit))))
+}
+
+// Bakes triangle attributes into every node whose cell a triangle overlaps,
+// descending only into child cells the triangle reaches. Nodes shared
+// between several cells average all of them.
+pub fn bake(nodes: &[Node], scene: &Scene, cube: &AABB, attributes: &TriangleAttributes) -> NodeAttributes {
+  let channel_count = attributes.names.len();
+  let mut sums = vec![vec![0.0f64; nodes.len()]; channel_count];
+  let mut counts = vec![vec![0u32; nodes.len()]; channel_count];
+
+  let extent = (cube.max[0] - cube.min[0]).max(f32::MIN_POSITIVE);
+  // Keeps cells that only touch a triangle from being lost to rounding
+  let slack = 1e-6;
+
+  let mut stack: Vec<(usize, [f32; 3], f32)> = Vec::new();
+  for (t, tri) in scene.triangles_indexed.iter().enumerate() {
+    // Triangle in the pool's normalized unit cube
+    let corners = tri.v_idx.map(|v| {
+      let p = scene.vertices[v];
+      [0, 1, 2].map(|k| (p[k] - cube.min[k]) / extent)
+    });
+
+    if nodes.is_empty() || !triangle_overlaps_cube(&corners, [0.5; 3], 0.5 + slack) {
+      continue;
+    }
+    stack.push((0, [0.0; 3], 1.0));
+
+    while let Some((node, cell_min, size)) = stack.pop() {
+      for c in 0..channel_count {
+        let value = attributes.values[c][t];
+        if !value.is_nan() {
+          sums[c][node] += value as f64;
+          counts[c][node] += 1;
+        }
+      }
+
+      let half = size * 0.5;
+      for slot in 0..8 {
+        let child = match nodes[node].child(slot) {
+          Some(child) if child < nodes.len() => child,
+          _ => continue,
+        };
+        let child_min = [0, 1, 2].map(|k| cell_min[k] + if slot & (1 << k) != 0 { half } else { 0.0 });
+        let center = child_min.map(|m| m + half * 0.5);
+        if triangle_overlaps_cube(&corners, center, half * (0.5 + slack)) {
+          stack.push((child, child_min, half));
+        }
+      }
+    }
+  }
+
+  let values = sums
+    .iter()
+    .zip(counts.iter())
+    .map(|(sum, count)| {
+      sum
+        .iter()
+        .zip(count.iter())
+        .map(|(&s, &n)| if n > 0 { (s / n as f64) as f32 } else { f32::NAN })
+        .collect()
+    })
+    .collect();
+
+  NodeAttributes {
+    names: attributes.names.clone(),
+    values,
+  }
+}
+
+pub fn write_attributes<P: AsRef<Path>>(attributes: &NodeAttributes, path: P) -> io::Result<()> {
+  let node_count = attributes.values.first().map_or(0, |v| v.len());
+  let mut file = BufWriter::new(File::create(path)?);
+
+  file.write_all(ATTRIBUTE_MAGIC)?;
+  file.write_all(&ATTRIBUTE_VERSION.to_le_bytes())?;
+  file.write_all(&(node_count as u64).to_le_bytes())?;
+  file.write_all(&(attributes.names.len() as u32).to_le_bytes())?;
+
+  for name in &attributes.names {
+    file.write_all(&(name.len() as u32).to_le_bytes())?;
+    file.write_all(name.as_bytes())?;
+  }
+
+  for channel in &attributes.values {
+    for value in channel {
+      file.write_all(&value.to_le_bytes())?;
+    }
+  }
+
+  file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::scene_loader::TriIndexed;
+  use crate::test_pools::pool_from_cells;
+
+  #[test]
+  fn large_triangle_reaches_every_leaf() {
+    // Half of the z = 0.3 plane, cut by x + y = 1
+    let depth = 6;
+    let n = (1u32 << depth) as f32;
+    let plane_z = (0.3 * n) as u32;
+    let nodes = pool_from_cells(depth, &|[x, y, z]| z == plane_z && (x + y) as f32 + 1.0 <= n);
+
+    let scene = Scene {
+      vertices: vec![[0.0, 0.0, 0.3], [1.0, 0.0, 0.3], [0.0, 1.0, 0.3]],
+      triangles_indexed: vec![TriIndexed {
+        v_idx: [0, 1, 2],
+        ..Default::default()
+      }],
+      ..Default::default()
+    };
+    let cube = AABB {
+      min: [0.0; 3],
+      max: [1.0; 3],
+    };
+    let attributes = TriangleAttributes {
+      names: vec![String::from("ao")],
+      values: vec![vec![0.5]],
+    };
+
+    let baked = bake(&nodes, &scene, &cube, &attributes);
+    let mut leaf_nodes = 0;
+    for (index, node) in nodes.iter().enumerate() {
+      if node.children.iter().any(|&c| c < 0) {
+        leaf_nodes += 1;
+        assert_eq!(baked.values[0][index], 0.5, "node {} got no value", index);
+      }
+    }
+    assert!(leaf_nodes > 100);
+  }
+
+  #[test]
+  fn csv_and_json_sidecars_agree() {
+    let dir = std::env::temp_dir().join(format!("oasis-attributes-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let csv = dir.join("attributes.csv");
+    let json = dir.join("attributes.json");
+    fs::write(&csv, "triangle, heat, stress\n0, 1.5, -2\n2, 3, 0.25\n").unwrap();
+    fs::write(&json, r#"{ "stress": [-2, null, 0.25], "heat": [1.5, null, 3] }"#).unwrap();
+
+    let from_csv = load(&csv, 3).unwrap();
+    let from_json = load(&json, 3).unwrap();
+    assert_eq!(from_csv.names, ["heat", "stress"]);
+    assert_eq!(from_json.names, from_csv.names);
+    for (a, b) in from_json.values.iter().zip(&from_csv.values) {
+      assert_eq!(a[0], b[0]);
+      assert!(a[1].is_nan() && b[1].is_nan());
+      assert_eq!(a[2], b[2]);
+    }
+
+    fs::write(&json, r#"{ "heat": [1.5, 2] }"#).unwrap();
+    assert!(load(&json, 3).unwrap_err().contains("has 2 values"));
+    fs::write(&json, r#"{ "heat": [1.5, "hot", 3] }"#).unwrap();
+    assert!(load(&json, 3).unwrap_err().contains("triangle 1"));
+    fs::write(&json, "[1, 2, 3]").unwrap();
+    assert!(load(&json, 3).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn triangle_cube_overlap() {
+    let tri = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    assert!(triangle_overlaps_cube(&tri, [0.25, 0.25, 0.1], 0.2));
+    // Beyond the hypotenuse, and above the plane
+    assert!(!triangle_overlaps_cube(&tri, [0.8, 0.8, 0.0], 0.1));
+    assert!(!triangle_overlaps_cube(&tri, [0.25, 0.25, 0.5], 0.2));
+    // Only an edge passes through
+    assert!(triangle_overlaps_cube(&tri, [0.5, -0.05, 0.0], 0.1));
+  }
+}
@@ -0,0 +1,142 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Parses a numeric argument, rejecting values outside `[min, max]`. Parsing
+// never depends on the system locale, so a decimal comma gets a hint instead
+// of a confusing failure.
+pub fn parse_number<T>(name: &str, value: &str, min: T, max: T) -> Result<T, String>
+where
+  T: FromStr + PartialOrd + Display + Copy,
+{
+  let trimmed = value.trim();
+  match trimmed.parse::<T>() {
+    Ok(number) if number >= min && number <= max => Ok(number),
+    Ok(_) => Err(format!("{} must be between {} and {}, got '{}'", name, min, max, value)),
+    Err(_) => {
+      let mut message = format!("{} must be a number between {} and {}, got '{}'", name, min, max, value);
+      if let Some(hint) = hint(trimmed, |v| v.parse::<T>().is_ok()) {
+        message.push_str(&format!(" ({})", hint));
+      }
+      Err(message)
+    }
+  }
+}
+
+// Like `parse_number`, with no upper bound
+pub fn parse_positive(name: &str, value: &str) -> Result<f32, String> {
+  let trimmed = value.trim();
+  match trimmed.parse::<f32>() {
+    Ok(number) if number > 0.0 && number.is_finite() => Ok(number),
+    Ok(_) => Err(format!("{} must be a positive number, got '{}'", name, value)),
+    Err(_) => {
+      let mut message = format!("{} must be a positive number, got '{}'", name, value);
+      if let Some(hint) = hint(trimmed, |v| v.parse::<f32>().is_ok()) {
+        message.push_str(&format!(" ({})", hint));
+      }
+      Err(message)
+    }
+  }
+}
+
+// True for values that are clearly meant as file paths
+pub fn looks_like_path(value: &str) -> bool {
+  value.contains('/')
+    || value.contains('\\')
+    || Path::new(value).extension().is_some_and(|ext| !ext.to_string_lossy().chars().all(|c| c.is_ascii_digit()))
+    || Path::new(value).exists()
+}
+
+fn hint(value: &str, parses: impl Fn(&str) -> bool) -> Option<String> {
+  if value.contains(',') && parses(&value.replace(',', ".")) {
+    return Some(format!("use '.' as the decimal separator, e.g. '{}'", value.replace(',', ".")));
+  }
+  if looks_like_path(value) {
+    return Some(String::from("this looks like a file path, are the arguments in the right order?"));
+  }
+  None
+}
+
+// Arguments are read with `env::args_os` so paths that aren't valid UTF-8
+// survive. Options and numbers are plain text, anything unconvertible simply
+// won't match or parse.
+pub fn text(arg: &OsStr) -> String {
+  arg.to_string_lossy().into_owned()
+}
+
+// `<name>.<extension>`, appended rather than replacing an extension so
+// output names containing dots are kept whole
+pub fn output_path(name: &Path, extension: &str) -> PathBuf {
+  let mut path = OsString::from(name.as_os_str());
+  path.push(".");
+  path.push(extension);
+  PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn numbers_in_range_parse() {
+    assert_eq!(parse_number("depth", " 12 ", 1u8, 16), Ok(12));
+    assert_eq!(parse_number("scale", "0.5", 0.0f32, 1.0), Ok(0.5));
+  }
+
+  #[test]
+  fn out_of_range_values_name_the_range() {
+    assert_eq!(
+      parse_number("depth", "20", 1u8, 16),
+      Err(String::from("depth must be between 1 and 16, got '20'"))
+    );
+    assert_eq!(
+      parse_number("depth", "0", 1u8, 16),
+      Err(String::from("depth must be between 1 and 16, got '0'"))
+    );
+  }
+
+  #[test]
+  fn non_numbers_are_rejected_without_a_hint() {
+    assert_eq!(
+      parse_number("depth", "deep", 1u8, 16),
+      Err(String::from("depth must be a number between 1 and 16, got 'deep'"))
+    );
+  }
+
+  #[test]
+  fn decimal_commas_get_a_hint() {
+    let error = parse_number("scale", "0,5", 0.0f32, 1.0).unwrap_err();
+    assert!(error.ends_with("(use '.' as the decimal separator, e.g. '0.5')"), "{}", error);
+    let error = parse_positive("--voxel-size", "0,25").unwrap_err();
+    assert!(error.contains("e.g. '0.25'"), "{}", error);
+    // Not a decimal either way, so no hint
+    assert!(!parse_number("depth", "1,2,3", 1u8, 16).unwrap_err().contains("decimal"));
+  }
+
+  #[test]
+  fn swapped_paths_get_a_hint() {
+    let error = parse_number("depth", "models/scene.obj", 1u8, 16).unwrap_err();
+    assert!(error.ends_with("(this looks like a file path, are the arguments in the right order?)"), "{}", error);
+    assert!(looks_like_path("scene.obj"));
+    assert!(looks_like_path("C:\\scene"));
+    assert!(!looks_like_path("12"));
+    assert!(!looks_like_path("1.5"));
+  }
+}
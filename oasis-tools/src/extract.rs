@@ -0,0 +1,375 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use oasis_format::{read_nodes, write_nodes, Node, NodeArena};
+
+use crate::cli;
+use crate::scene_loader::AABB;
+
+// Octree cell covered by a node, in the pool's normalized [0, 1] space
+#[derive(Debug, Clone, Copy)]
+pub struct Cell {
+  pub node: usize,
+  pub level: u32,
+  pub min: [f32; 3],
+  pub size: f32,
+  // Color accumulated from the ancestors, not including `node` itself
+  pub yuv: [f32; 3],
+}
+
+pub enum Selection {
+  Node(usize),
+  Region { min: [f32; 3], max: [f32; 3] },
+}
+
+// Finds the deepest node whose cell fully contains the region `[min, max]`.
+// Child slot bits select the upper half of the cell (bit 0: x, 1: y, 2: z).
+pub fn find_region_cell(nodes: &[Node], min: [f32; 3], max: [f32; 3]) -> Result<Cell, String> {
+  if nodes.is_empty() {
+    return Err(String::from("Node pool is empty"));
+  }
+  for k in 0..3 {
+    if min[k] > max[k] || min[k] < 0.0 || max[k] > 1.0 {
+      return Err(format!(
+        "Region {:?} - {:?} must be non-empty and inside the unit cube",
+        min, max
+      ));
+    }
+  }
+
+  let mut cell = Cell {
+    node: 0,
+    level: 0,
+    min: [0.0; 3],
+    size: 1.0,
+    yuv: [0.0; 3],
+  };
+
+  loop {
+    let half = cell.size * 0.5;
+    let mut slot = 0usize;
+    let mut child_min = cell.min;
+
+    for k in 0..3 {
+      let mid = cell.min[k] + half;
+      if min[k] >= mid {
+        slot |= 1 << k;
+        child_min[k] = mid;
+      } else if max[k] > mid {
+        // Region straddles the split plane, this cell is the smallest fit
+        return Ok(cell);
+      }
+    }
+
+    match nodes[cell.node].child(slot) {
+      Some(child) if child < nodes.len() => {
+        let parent_yuv = nodes[cell.node].yuv;
+        cell = Cell {
+          node: child,
+          level: cell.level + 1,
+          min: child_min,
+          size: half,
+          yuv: [0, 1, 2].map(|k| cell.yuv[k] + parent_yuv[k]),
+        };
+      }
+      // Leaves and empty slots can't be re-rooted on their own
+      _ => return Ok(cell),
+    }
+  }
+}
+
+// First cell found for `node` searching breadth-first from the root. A DAG
+// node can cover several cells, their ancestor colors may differ.
+pub fn find_node_cell(nodes: &[Node], node: usize) -> Option<Cell> {
+  if node >= nodes.len() {
+    return None;
+  }
+
+  let root = Cell {
+    node: 0,
+    level: 0,
+    min: [0.0; 3],
+    size: 1.0,
+    yuv: [0.0; 3],
+  };
+  let mut seen = vec![false; nodes.len()];
+  let mut queue = VecDeque::from([root]);
+  seen[0] = true;
+
+  while let Some(cell) = queue.pop_front() {
+    if cell.node == node {
+      return Some(cell);
+    }
+    let parent = &nodes[cell.node];
+    let half = cell.size * 0.5;
+    for slot in 0..8 {
+      match parent.child(slot) {
+        Some(child) if child < nodes.len() && !seen[child] => {
+          seen[child] = true;
+          queue.push_back(Cell {
+            node: child,
+            level: cell.level + 1,
+            min: [0, 1, 2].map(|k| cell.min[k] + if slot & (1 << k) != 0 { half } else { 0.0 }),
+            size: half,
+            yuv: [0, 1, 2].map(|k| cell.yuv[k] + parent.yuv[k]),
+          });
+        }
+        _ => {}
+      }
+    }
+  }
+  None
+}
+
+// Converts a world-space region into the pool's normalized space. Pools are
+// built over the cube grown from the scene bounds' min corner, so `bounds`
+// must be the scene bounds the pool was built with.
+pub fn world_to_pool(bounds: &AABB, min: [f32; 3], max: [f32; 3]) -> Result<([f32; 3], [f32; 3]), String> {
+  let cube = bounds.cube();
+  let extent = cube.max_extent();
+  if extent <= 0.0 {
+    return Err(format!("Bounds {:?} - {:?} must have a positive extent", bounds.min, bounds.max));
+  }
+  let normalize = |p: [f32; 3]| [0, 1, 2].map(|k| (p[k] - cube.min[k]) / extent);
+  Ok((normalize(min), normalize(max)))
+}
+
+// Copies every node reachable from `root` into a new pool with `root` at
+// index 0, remapping child pointers. Shared (DAG) nodes are copied once.
+// `base_yuv` is the color the ancestors added on the way down, folded into
+// the new root so the subtree keeps its colors standalone. A node's color
+// only reaches the cells below its children, so leaves directly in the new
+// root's slots come out black; the format has nowhere to put their color.
+pub fn extract_subtree(nodes: &[Node], root: usize, base_yuv: [f32; 3]) -> Result<Vec<Node>, String> {
+  if root >= nodes.len() {
+    return Err(format!("Node {} is out of range (pool has {} nodes)", root, nodes.len()));
+  }
+
+  let mut arena = NodeArena::from_nodes(nodes);
+  arena.compact(root)?;
+  let root_yuv = &mut arena.get_mut(0).yuv;
+  for (channel, base) in root_yuv.iter_mut().zip(base_yuv) {
+    *channel += base;
+  }
+  Ok(arena.to_vec())
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./builder extract <pool.svdag> --node <index> [output_name]");
+  eprintln!("       ./builder extract <pool.svdag> --region <x0,y0,z0,x1,y1,z1> [--bounds <x0,y0,z0,x1,y1,z1>] [output_name]");
+  eprintln!("Regions are given in the pool's normalized [0, 1] space, or in world units");
+  eprintln!("when --bounds gives the world-space scene bounds the pool was built with.");
+  std::process::exit(1);
+}
+
+fn fail(message: &str) -> ! {
+  eprintln!("Error: {}", message);
+  print_usage();
+}
+
+pub fn parse_region(value: &str) -> Option<([f32; 3], [f32; 3])> {
+  let values: Vec<f32> = value
+    .split(',')
+    .map(|v| v.trim().parse::<f32>())
+    .collect::<Result<_, _>>()
+    .ok()?;
+  if values.len() != 6 {
+    return None;
+  }
+  Some(([values[0], values[1], values[2]], [values[3], values[4], values[5]]))
+}
+
+pub fn run(mut args: impl Iterator<Item = OsString>) {
+  let mut selection: Option<Selection> = None;
+  let mut bounds: Option<AABB> = None;
+  let mut positional: Vec<OsString> = Vec::new();
+
+  while let Some(arg) = args.next() {
+    match cli::text(&arg).as_str() {
+      "--node" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        let index = cli::parse_number("--node", &value, 0, usize::MAX).unwrap_or_else(|e| fail(&e));
+        selection = Some(Selection::Node(index));
+      }
+      "--region" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        let (min, max) = parse_region(&value).unwrap_or_else(|| {
+          fail(&format!("--region must be x0,y0,z0,x1,y1,z1, got '{}'", value))
+        });
+        selection = Some(Selection::Region { min, max });
+      }
+      "--bounds" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        let (min, max) = parse_region(&value).unwrap_or_else(|| {
+          fail(&format!("--bounds must be x0,y0,z0,x1,y1,z1, got '{}'", value))
+        });
+        bounds = Some(AABB { min, max });
+      }
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ => positional.push(arg),
+    }
+  }
+
+  let mut positional = positional.into_iter();
+  let input = PathBuf::from(positional.next().unwrap_or_else(|| print_usage()));
+  let output_name = positional.next().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("extract"));
+  let selection = match (selection.unwrap_or_else(|| print_usage()), bounds) {
+    (Selection::Region { min, max }, Some(bounds)) => {
+      let (min, max) = world_to_pool(&bounds, min, max).unwrap_or_else(|e| fail(&e));
+      println!("World region maps to {:?} - {:?} in pool space.", min, max);
+      Selection::Region { min, max }
+    }
+    (Selection::Node(_), Some(_)) => fail("--bounds only applies to --region"),
+    (selection, None) => selection,
+  };
+
+  let nodes = match read_nodes(&input) {
+    Ok(nodes) => nodes,
+    Err(e) => {
+      eprintln!("Error reading node pool '{}': {}", input.display(), e);
+      std::process::exit(1);
+    }
+  };
+  println!("Loaded {} nodes from '{}'.", nodes.len(), input.display());
+
+  let cell = match selection {
+    Selection::Node(index) => match find_node_cell(&nodes, index) {
+      Some(cell) => cell,
+      None => {
+        eprintln!("Node {} is not reachable from the root (pool has {} nodes)", index, nodes.len());
+        std::process::exit(1);
+      }
+    },
+    Selection::Region { min, max } => match find_region_cell(&nodes, min, max) {
+      Ok(cell) => cell,
+      Err(e) => {
+        eprintln!("{}", e);
+        std::process::exit(1);
+      }
+    },
+  };
+  println!(
+    "Extracting node {} at level {} (cell min {:?}, size {}).",
+    cell.node, cell.level, cell.min, cell.size
+  );
+  let root = cell.node;
+
+  let subtree = match extract_subtree(&nodes, root, cell.yuv) {
+    Ok(subtree) => subtree,
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    }
+  };
+
+  println!("Extracted {} nodes rooted at node {}.", subtree.len(), root);
+  write_nodes(&subtree, cli::output_path(&output_name, "svdag")).expect("Failed to serialize node pool");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::test_pools::{sample_pool, voxels};
+
+  // Cells of the source's 2^level grid that fall inside `cell`, in `voxels`
+  // order. Leaves in the root's slots lose their color once re-rooted.
+  fn expected_cells(nodes: &[Node], level: u32, cell: &Cell) -> Vec<Option<[f32; 3]>> {
+    let grid = voxels(nodes, level);
+    let n = 1usize << level;
+    let span = n >> cell.level;
+    let start = cell.min.map(|m| (m * n as f32) as usize);
+    let mut cells = Vec::new();
+    for z in 0..span {
+      for y in 0..span {
+        for x in 0..span {
+          let slot = [x, y, z].iter().enumerate().map(|(k, &c)| (2 * c / span) << k).sum::<usize>();
+          let voxel = grid[start[0] + x + n * (start[1] + y + n * (start[2] + z))];
+          cells.push(if nodes[cell.node].children[slot] < 0 { voxel.map(|_| [0.0; 3]) } else { voxel });
+        }
+      }
+    }
+    cells
+  }
+
+  fn check_round_trip(nodes: &[Node], cell: &Cell) -> Vec<Option<[f32; 3]>> {
+    let subtree = extract_subtree(nodes, cell.node, cell.yuv).unwrap();
+    oasis_format::validate_children(&subtree).unwrap();
+    let cells = voxels(&subtree, 3 - cell.level);
+    assert_eq!(cells, expected_cells(nodes, 3, cell));
+    cells
+  }
+
+  #[test]
+  fn region_round_trip() {
+    let nodes = sample_pool();
+    // Inside root slot 4 but straddling the middle of that cell
+    let cell = find_region_cell(&nodes, [0.1, 0.1, 0.6], [0.4, 0.4, 0.9]).unwrap();
+    assert_eq!((cell.node, cell.level), (1, 1));
+    assert_eq!(cell.min, [0.0, 0.0, 0.5]);
+    assert_eq!(cell.size, 0.5);
+    let cells = check_round_trip(&nodes, &cell);
+    // The shared node's leaves pick up the rebased ancestor colors
+    assert!(cells.contains(&Some([120.0, 128.0, 128.0])));
+  }
+
+  #[test]
+  fn node_round_trip() {
+    let nodes = sample_pool();
+    // Node 3 is shared, the first cell found is under node 1
+    let cell = find_node_cell(&nodes, 3).unwrap();
+    assert_eq!(cell.level, 2);
+    assert_eq!(cell.min, [0.25, 0.25, 0.0]);
+    assert_eq!(cell.yuv, [120.0, 128.0, 128.0]);
+    check_round_trip(&nodes, &cell);
+    check_round_trip(&nodes, &find_node_cell(&nodes, 2).unwrap());
+  }
+
+  #[test]
+  fn regions_stop_at_leaves_and_empty_slots() {
+    let nodes = sample_pool();
+    // Solid leaf in root slot 2 and empty root slot 3
+    assert_eq!(find_region_cell(&nodes, [0.1, 0.6, 0.1], [0.2, 0.7, 0.2]).unwrap().node, 0);
+    assert_eq!(find_region_cell(&nodes, [0.6, 0.6, 0.1], [0.7, 0.7, 0.2]).unwrap().node, 0);
+    assert!(find_region_cell(&nodes, [0.5, 0.5, 0.5], [1.5, 0.6, 0.6]).is_err());
+    assert!(extract_subtree(&nodes, 4, [0.0; 3]).is_err());
+  }
+
+  #[test]
+  fn world_regions_map_into_the_bounding_cube() {
+    // The cube grows from the min corner to the largest extent (4 along x)
+    let bounds = AABB {
+      min: [-2.0, 0.0, 10.0],
+      max: [2.0, 1.0, 12.0],
+    };
+    let (min, max) = world_to_pool(&bounds, [-2.0, 0.0, 10.0], [0.0, 1.0, 13.0]).unwrap();
+    assert_eq!(min, [0.0, 0.0, 0.0]);
+    assert_eq!(max, [0.5, 0.25, 0.75]);
+
+    let flat = AABB {
+      min: [1.0; 3],
+      max: [1.0; 3],
+    };
+    assert!(world_to_pool(&flat, [0.0; 3], [1.0; 3]).is_err());
+  }
+}
@@ -0,0 +1,92 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+// 64-bit FNV-1a. Not cryptographic, but stable across platforms and Rust
+// releases, which is what on-disk keys need (std's DefaultHasher isn't).
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1a64(u64);
+
+impl Fnv1a64 {
+  const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+  const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+  pub fn new() -> Self {
+    Fnv1a64(Self::OFFSET_BASIS)
+  }
+
+  pub fn update(&mut self, bytes: &[u8]) {
+    for &b in bytes {
+      self.0 ^= b as u64;
+      self.0 = self.0.wrapping_mul(Self::PRIME);
+    }
+  }
+
+  pub fn finish(&self) -> u64 {
+    self.0
+  }
+}
+
+impl Default for Fnv1a64 {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+// 128-bit FNV-1a, for keys that a 64-bit hash would collide on too easily
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1a128(u128);
+
+impl Fnv1a128 {
+  const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+  const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+  pub fn new() -> Self {
+    Fnv1a128(Self::OFFSET_BASIS)
+  }
+
+  pub fn update(&mut self, bytes: &[u8]) {
+    for &b in bytes {
+      self.0 ^= b as u128;
+      self.0 = self.0.wrapping_mul(Self::PRIME);
+    }
+  }
+
+  pub fn finish(&self) -> u128 {
+    self.0
+  }
+}
+
+impl Default for Fnv1a128 {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reference_values() {
+    let mut hasher = Fnv1a64::new();
+    hasher.update(b"a");
+    assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+
+    let mut hasher = Fnv1a128::new();
+    hasher.update(b"a");
+    assert_eq!(hasher.finish(), 0xd228_cb69_6f1a_8caf_7891_2b70_4e4a_8964);
+  }
+}
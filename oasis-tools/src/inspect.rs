@@ -0,0 +1,331 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use image::{Rgba, RgbaImage};
+
+use oasis_format::{yuv_to_rgb, Node};
+
+use crate::cli;
+use crate::extract::{parse_region, world_to_pool};
+use crate::palette::{extract_palette, render_palette};
+use crate::scene_loader::AABB;
+use crate::split::read_pool;
+
+const DEFAULT_SLICE_SIZE: u32 = 512;
+const MAX_SLICE_SIZE: u32 = 16384;
+const MAX_PALETTE_SIZE: usize = 256;
+
+// Color of an empty cell in slice images
+const EMPTY_PIXEL: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+pub struct PoolStats {
+  pub nodes: usize,
+  pub leaf_slots: usize,
+  pub empty_slots: usize,
+  pub height: u32,
+}
+
+// Counts slot kinds and the height of the tree below the root
+pub fn pool_stats(nodes: &[Node]) -> PoolStats {
+  let mut stats = PoolStats {
+    nodes: nodes.len(),
+    leaf_slots: 0,
+    empty_slots: 0,
+    height: 0,
+  };
+
+  for node in nodes {
+    for &child in &node.children {
+      if child < 0 {
+        stats.leaf_slots += 1;
+      } else if child == 0 {
+        stats.empty_slots += 1;
+      }
+    }
+  }
+
+  // Children are not guaranteed to follow their parents, so resolve heights
+  // with an explicit stack instead of a single reverse pass.
+  let mut heights: Vec<Option<u32>> = vec![None; nodes.len()];
+  let mut stack: Vec<usize> = if nodes.is_empty() { Vec::new() } else { vec![0] };
+  while let Some(&index) = stack.last() {
+    if heights[index].is_some() {
+      stack.pop();
+      continue;
+    }
+    let mut pending = false;
+    let mut height = 1;
+    for slot in 0..8 {
+      if let Some(child) = nodes[index].child(slot) {
+        match heights[child] {
+          Some(h) => height = height.max(h + 1),
+          None => {
+            stack.push(child);
+            pending = true;
+          }
+        }
+      }
+    }
+    if !pending {
+      heights[index] = Some(height);
+      stack.pop();
+    }
+  }
+  stats.height = heights.first().copied().flatten().unwrap_or(0);
+
+  stats
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Axis {
+  X,
+  Y,
+  Z,
+}
+
+// Descends to the leaf containing `p` (normalized coordinates), returning the
+// YUV sum the shader would shade it with, or None if the point is empty.
+pub fn sample_point(nodes: &[Node], p: [f32; 3]) -> Option<[f32; 3]> {
+  let mut node = 0usize;
+  let mut cell_min = [0.0f32; 3];
+  let mut size = 1.0f32;
+  let mut yuv = [0.0f32; 3];
+
+  while node < nodes.len() {
+    size *= 0.5;
+    let mut slot = 0usize;
+    for k in 0..3 {
+      if p[k] >= cell_min[k] + size {
+        slot |= 1 << k;
+        cell_min[k] += size;
+      }
+    }
+
+    let child = nodes[node].children[slot];
+    if child < 0 {
+      return Some(yuv);
+    }
+    if child == 0 {
+      return None;
+    }
+
+    // Like frag.glsl, a node's color is only added when descending past it
+    for (c, node_c) in yuv.iter_mut().zip(&nodes[node].yuv) {
+      *c += node_c;
+    }
+    node = (child - 1) as usize;
+  }
+  None
+}
+
+// Renders the plane `axis = value` (normalized) as a `size` x `size` image.
+// Y slices are top-down (x right, z down), X and Z slices have y up.
+pub fn render_slice(nodes: &[Node], axis: Axis, value: f32, size: u32) -> RgbaImage {
+  let mut image = RgbaImage::new(size, size);
+  let value = value.clamp(0.0, 1.0 - f32::EPSILON);
+
+  for row in 0..size {
+    for col in 0..size {
+      let u = (col as f32 + 0.5) / size as f32;
+      let v = (row as f32 + 0.5) / size as f32;
+
+      let p = match axis {
+        Axis::X => [value, 1.0 - v, u],
+        Axis::Y => [u, value, v],
+        Axis::Z => [u, 1.0 - v, value],
+      };
+
+      let pixel = match sample_point(nodes, p) {
+        Some(yuv) => {
+          let [r, g, b] = yuv_to_rgb(yuv);
+          Rgba([r, g, b, 255])
+        }
+        None => EMPTY_PIXEL,
+      };
+      image.put_pixel(col, row, pixel);
+    }
+  }
+
+  image
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./builder inspect <pool.svdag|pool.svtopo> [--slice <x|y|z>=<value> --out <slice.png> [--size <pixels>]]");
+  eprintln!("                       [--bounds <x0,y0,z0,x1,y1,z1>] [--palette <colors> [--palette-out <palette.png>]]");
+  eprintln!("Slice values are given in the pool's normalized [0, 1] space, or in world units");
+  eprintln!("when --bounds gives the world-space scene bounds the pool was built with.");
+  std::process::exit(1);
+}
+
+fn fail(message: &str) -> ! {
+  eprintln!("Error: {}", message);
+  print_usage();
+}
+
+fn parse_slice(value: &str) -> Option<(Axis, f32)> {
+  let (axis, value) = value.split_once('=')?;
+  let axis = match axis.trim() {
+    "x" | "X" => Axis::X,
+    "y" | "Y" => Axis::Y,
+    "z" | "Z" => Axis::Z,
+    _ => return None,
+  };
+  Some((axis, value.trim().parse().ok()?))
+}
+
+pub fn run(mut args: impl Iterator<Item = OsString>) {
+  let mut slice: Option<(Axis, f32)> = None;
+  let mut bounds: Option<AABB> = None;
+  let mut out: Option<PathBuf> = None;
+  let mut size = DEFAULT_SLICE_SIZE;
+  let mut palette_size: Option<usize> = None;
+  let mut palette_out: Option<PathBuf> = None;
+  let mut positional: Vec<OsString> = Vec::new();
+
+  while let Some(arg) = args.next() {
+    match cli::text(&arg).as_str() {
+      "--slice" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        slice = Some(parse_slice(&value).unwrap_or_else(|| {
+          fail(&format!("--slice must be <x|y|z>=<value>, got '{}'", value))
+        }));
+      }
+      "--bounds" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        let (min, max) = parse_region(&value).unwrap_or_else(|| {
+          fail(&format!("--bounds must be x0,y0,z0,x1,y1,z1, got '{}'", value))
+        });
+        bounds = Some(AABB { min, max });
+      }
+      "--out" => out = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--size" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        size = cli::parse_number("--size", &value, 1, MAX_SLICE_SIZE).unwrap_or_else(|e| fail(&e));
+      }
+      "--palette" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        palette_size = Some(cli::parse_number("--palette", &value, 1, MAX_PALETTE_SIZE).unwrap_or_else(|e| fail(&e)));
+      }
+      "--palette-out" => palette_out = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ => positional.push(arg),
+    }
+  }
+
+  let mut positional = positional.into_iter();
+  let input = PathBuf::from(positional.next().unwrap_or_else(|| print_usage()));
+
+  let nodes = match read_pool(&input) {
+    Ok(nodes) => nodes,
+    Err(e) => {
+      eprintln!("Error reading node pool '{}': {}", input.display(), e);
+      std::process::exit(1);
+    }
+  };
+
+  let stats = pool_stats(&nodes);
+  println!("Pool '{}':", input.display());
+  println!("  nodes:       {}", stats.nodes);
+  println!("  leaf slots:  {}", stats.leaf_slots);
+  println!("  empty slots: {}", stats.empty_slots);
+  println!("  height:      {}", stats.height);
+
+  if let Some((axis, value)) = slice {
+    let out = out.unwrap_or_else(|| print_usage());
+    let normalized = match &bounds {
+      Some(bounds) => {
+        let (p, _) = world_to_pool(bounds, [value; 3], [value; 3]).unwrap_or_else(|e| fail(&e));
+        let normalized = p[axis as usize];
+        println!("World {:?} = {} maps to {} in pool space.", axis, value, normalized);
+        normalized
+      }
+      None => value,
+    };
+    let image = render_slice(&nodes, axis, normalized, size);
+    image.save(&out).expect("Failed to write slice image");
+    println!("Wrote {:?} slice at {} to '{}'.", axis, value, out.display());
+  }
+
+  if let Some(k) = palette_size {
+    let palette = extract_palette(&nodes, k);
+    println!("Palette ({} colors, share of filled volume):", palette.len());
+    for entry in &palette {
+      let [r, g, b] = entry.rgb;
+      println!("  #{:02x}{:02x}{:02x}  {:5.1}%", r, g, b, entry.share * 100.0);
+    }
+
+    let out = palette_out.unwrap_or_else(|| input.with_extension("palette.png"));
+    render_palette(&palette).save(&out).expect("Failed to write palette image");
+    println!("Wrote palette to '{}'.", out.display());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::test_pools::sample_pool;
+
+  #[test]
+  fn slice_pixels_match_sampled_points() {
+    let nodes = sample_pool();
+    let size = 8;
+    for (axis, value) in [(Axis::X, 0.3), (Axis::Y, 0.1), (Axis::Y, 0.6), (Axis::Z, 0.8)] {
+      let image = render_slice(&nodes, axis, value, size);
+      let (mut filled, mut empty) = (0, 0);
+      for (col, row, pixel) in image.enumerate_pixels() {
+        let u = (col as f32 + 0.5) / size as f32;
+        let v = (row as f32 + 0.5) / size as f32;
+        let p = match axis {
+          Axis::X => [value, 1.0 - v, u],
+          Axis::Y => [u, value, v],
+          Axis::Z => [u, 1.0 - v, value],
+        };
+        let expected = match sample_point(&nodes, p) {
+          Some(yuv) => {
+            filled += 1;
+            let [r, g, b] = yuv_to_rgb(yuv);
+            Rgba([r, g, b, 255])
+          }
+          None => {
+            empty += 1;
+            EMPTY_PIXEL
+          }
+        };
+        assert_eq!(*pixel, expected, "{:?} = {} at ({}, {})", axis, value, col, row);
+      }
+      assert!(filled > 0 && empty > 0, "{:?} = {} is uniform", axis, value);
+    }
+  }
+
+  #[test]
+  fn slice_colors_follow_the_tree() {
+    let nodes = sample_pool();
+    let image = render_slice(&nodes, Axis::Y, 0.1, 4);
+    // Top-down, so row 0 is z near 0. Root slot 0 leads to the leaf in slot 0
+    // of node 1, which only picks up the root's color.
+    let [r, g, b] = yuv_to_rgb([100.0, 128.0, 128.0]);
+    assert_eq!(image.get_pixel(0, 0), &Rgba([r, g, b, 255]));
+    // Root slot 5 is empty
+    assert_eq!(image.get_pixel(3, 3), &EMPTY_PIXEL);
+  }
+}
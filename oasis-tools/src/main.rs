@@ -0,0 +1,439 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::HashSet;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use image::DynamicImage;
+use image::GenericImageView;
+
+mod attributes;
+use attributes::{write_attributes, TriangleAttributes, ATTRIBUTE_EXTENSION};
+
+mod cli;
+
+mod extract;
+mod hash;
+mod inspect;
+mod optimize;
+mod palette;
+mod patch;
+mod report;
+use report::BuildReport;
+
+mod split;
+
+mod scene_loader;
+use scene_loader::load_obj_scene;
+use scene_loader::{Scene, SceneOptions, Winding, AABB};
+
+mod texture_cache;
+use texture_cache::{CachedTexture, TextureCache};
+
+#[cfg(test)]
+mod test_pools;
+
+mod vfs;
+use vfs::Location;
+
+use oasis::{BuildConfig, NodePool, MAX_DEPTH};
+
+pub fn load_textures(
+  scene: &Scene,
+  obj_file: &Location,
+  c_scene: &mut oasis::Scene,
+  cache: Option<&TextureCache>,
+) -> Result<(), Box<dyn std::error::Error>> {
+  let mut loaded_textures: HashSet<String> = HashSet::new();
+
+  for material in &scene.materials {
+    if let Some(ref texture_name) = material.texture {
+      if loaded_textures.contains(texture_name) {
+        continue;
+      }
+
+      let texture_path = scene_loader::texture_path(obj_file, texture_name);
+      let source = texture_path
+        .read()
+        .map_err(|e| format!("failed to read texture '{}': {}", texture_path, e))?;
+      let key = TextureCache::key(&source);
+
+      let texture = match cache.and_then(|c| c.get(key)) {
+        Some(texture) => {
+          println!("Using cached texture '{}' for material '{}'.", texture_name, material.name);
+          texture
+        }
+        None => {
+          println!("Loading and flipping texture '{}' for material '{}'...", texture_name, material.name);
+
+          // Load and flip image vertically
+          let img: DynamicImage = image::load_from_memory(&source)?.flipv().to_rgb8().into();
+          let (width, height) = img.dimensions();
+          let texture = CachedTexture {
+            width,
+            height,
+            channels: 3,
+            data: img.into_rgb8().into_raw(),
+          };
+
+          if let Some(cache) = cache {
+            if let Err(e) = cache.put(key, &texture) {
+              eprintln!("Warning: failed to cache texture '{}': {}", texture_name, e);
+            }
+          }
+          texture
+        }
+      };
+
+      loaded_textures.insert(texture_name.clone());
+
+      c_scene.add_texture(texture_name, texture.width, texture.height, texture.channels, &texture.data)?;
+    }
+  }
+
+  Ok(())
+}
+
+// Hands `scene` to the library, to be voxelized inside `bbox`. Pools built
+// from the returned scene borrow it, so keep it alive while they're in use.
+pub fn create_c_scene<'a>(
+  scene: &'a Scene,
+  obj_file: &Location,
+  bbox: &AABB,
+  cache: Option<&TextureCache>,
+) -> Result<oasis::Scene<'a>, oasis::Error> {
+  let mut c_scene = oasis::Scene::new()?;
+  c_scene.set_vertices(&scene.vertices);
+  c_scene.set_tex_coords(&scene.texture_coords);
+  c_scene.set_raw_triangles(&scene.triangles);
+  c_scene.set_indexed_triangles(&scene.triangles_indexed);
+  c_scene.set_bounds(bbox.min, bbox.max);
+
+  for mat in &scene.materials {
+    let mut material = oasis::Material::new(&mat.name);
+    material.texture = mat.texture.as_deref();
+    material.diffuse = mat.diffuse;
+    material.specular = mat.specular;
+    material.ambient = mat.ambient;
+    material.exponent = mat.exponent;
+    c_scene.add_material(&material)?;
+  }
+
+  if let Err(e) = load_textures(scene, obj_file, &mut c_scene, cache) {
+    eprintln!("Error loading textures: {}", e);
+  }
+
+  Ok(c_scene)
+}
+
+enum Resolution {
+  Depth(u8),
+  VoxelSize(f32),
+}
+
+struct BuildArgs {
+  obj_file: Location,
+  resolution: Resolution,
+  step_level: u8,
+  output_name: PathBuf,
+  no_cache: bool,
+  cache_limit_mb: u64,
+  attributes: Option<PathBuf>,
+  vertex_color_attributes: bool,
+  split: bool,
+  report: Option<PathBuf>,
+  winding: Winding,
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./builder <model.obj> <depth> <step_level> [output_name]");
+  eprintln!("       ./builder <model.obj> --voxel-size <meters> <step_level> [output_name]");
+  eprintln!("       <model.obj> may also be an archive entry (assets.zip!models/scene.obj) or an http(s) URL");
+  eprintln!("       options: [--no-cache] [--cache-limit <MB>] [--attributes <file.csv|file.json>] [--vertex-color-attributes] [--split] [--report <out.json>] [--flip-winding | --auto-winding]");
+  eprintln!("       OBJ normals (vn) are never loaded, voxel normals follow the winding, so there is no --flip-normals");
+  eprintln!("       ./builder extract <pool.svdag> (--node <index> | --region <x0,y0,z0,x1,y1,z1>) [output_name]");
+  eprintln!("       ./builder inspect <pool.svdag|pool.svtopo> [--slice <x|y|z>=<value> --out <slice.png> [--size <pixels>]]");
+  eprintln!("       ./builder optimize <pool.svdag> --reorder <morton|bfs|dfs> [output_name]");
+  eprintln!("       ./builder patch <pool.svdag> <model.obj> <depth> <step_level> --region <x0,y0,z0,x1,y1,z1> [--bounds <x0,y0,z0,x1,y1,z1>] [output_name]");
+  std::process::exit(1);
+}
+
+// Prints `message` followed by the usage and exits
+fn fail(message: &str) -> ! {
+  eprintln!("Error: {}", message);
+  print_usage();
+}
+
+fn parse_args() -> BuildArgs {
+  let mut voxel_size: Option<f32> = None;
+  let mut no_cache = false;
+  let mut cache_limit_mb = texture_cache::DEFAULT_LIMIT_MB;
+  let mut attributes: Option<PathBuf> = None;
+  let mut vertex_color_attributes = false;
+  let mut split = false;
+  let mut report: Option<PathBuf> = None;
+  let mut winding = Winding::Keep;
+  let mut positional: Vec<OsString> = Vec::new();
+
+  let mut args = env::args_os().skip(1);
+  while let Some(arg) = args.next() {
+    match cli::text(&arg).as_str() {
+      "--voxel-size" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        voxel_size = Some(cli::parse_positive("--voxel-size", &value).unwrap_or_else(|e| fail(&e)));
+      }
+      "--no-cache" => no_cache = true,
+      "--cache-limit" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        cache_limit_mb = cli::parse_number("--cache-limit", &value, 0, u64::MAX / (1024 * 1024)).unwrap_or_else(|e| fail(&e));
+      }
+      "--attributes" => attributes = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--vertex-color-attributes" => vertex_color_attributes = true,
+      "--split" => split = true,
+      "--report" => report = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--flip-winding" => winding = Winding::Flip,
+      "--auto-winding" => winding = Winding::Auto,
+      "--flip-normals" => fail("OBJ normals are not loaded, so --flip-normals has nothing to flip. Use --flip-winding or --auto-winding"),
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ => positional.push(arg),
+    }
+  }
+
+  // A number where the model should be usually means swapped arguments
+  if let [first, second, ..] = positional.as_slice() {
+    let (first, second) = (cli::text(first), cli::text(second));
+    if first.trim().parse::<f32>().is_ok() && cli::looks_like_path(&second) {
+      fail(&format!(
+        "expected <model.obj> before the numbers, got '{}' then '{}', are the arguments swapped?",
+        first, second
+      ));
+    }
+  }
+
+  let mut positional = positional.into_iter();
+  let obj_file = Location::parse(&positional.next().unwrap_or_else(|| print_usage()));
+  if !obj_file.exists() {
+    fail(&format!("model '{}' does not exist or is not a file", obj_file));
+  }
+
+  // With --voxel-size the depth is derived from the scene, so it is not passed
+  let resolution = match voxel_size {
+    Some(size) => Resolution::VoxelSize(size),
+    None => {
+      let depth = cli::text(&positional.next().unwrap_or_else(|| print_usage()));
+      Resolution::Depth(cli::parse_number("depth", &depth, 1, MAX_DEPTH).unwrap_or_else(|e| fail(&e)))
+    }
+  };
+
+  let step_level = cli::text(&positional.next().unwrap_or_else(|| print_usage()));
+  let step_level = cli::parse_number("step_level", &step_level, 0, MAX_DEPTH).unwrap_or_else(|e| fail(&e));
+
+  // Provide a default output name if not given
+  let output_name = positional.next().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("out"));
+
+  if let Some(extra) = positional.next() {
+    fail(&format!("unexpected argument '{}'", cli::text(&extra)));
+  }
+
+  BuildArgs {
+    obj_file,
+    resolution,
+    step_level,
+    output_name,
+    no_cache,
+    cache_limit_mb,
+    attributes,
+    vertex_color_attributes,
+    split,
+    report,
+    winding,
+  }
+}
+
+fn main() {
+  match env::args_os().nth(1).as_deref().and_then(OsStr::to_str) {
+    Some("extract") => return extract::run(env::args_os().skip(2)),
+    Some("patch") => return patch::run(env::args_os().skip(2)),
+    Some("inspect") => return inspect::run(env::args_os().skip(2)),
+    Some("optimize") => return optimize::run(env::args_os().skip(2)),
+    _ => {}
+  }
+
+  let args = parse_args();
+  let obj_file = &args.obj_file;
+  let step_level = args.step_level;
+  let output_name = &args.output_name;
+
+  // Hashing inputs and outputs reads them again, so only do it for --report
+  let reporting = args.report.is_some();
+  let mut report = BuildReport::default();
+  let mut phase_start = Instant::now();
+
+  let scene_options = SceneOptions { winding: args.winding };
+  let scene = match load_obj_scene(obj_file, &scene_options) {
+    Ok(scene) => {
+      println!("OBJ file loaded successfully!");
+      scene
+    }
+    Err(e) => {
+      eprintln!("Error loading OBJ file: {}", e);
+      return;
+    }
+  };
+
+  let depth = match args.resolution {
+    Resolution::Depth(depth) => depth,
+    Resolution::VoxelSize(voxel_size) => {
+      let depth = scene.aabb.depth_for_voxel_size(voxel_size, MAX_DEPTH);
+      let actual = scene.aabb.max_extent() / (1u64 << depth) as f32;
+      let [x, y, z] = scene.aabb.grid_dimensions(depth);
+      println!(
+        "Voxel size {} m -> depth {} (actual voxel size {} m, grid {} x {} x {})",
+        voxel_size, depth, actual, x, y, z
+      );
+      if actual > voxel_size {
+        let warning = format!("depth clamped to {}, voxels are larger than requested.", MAX_DEPTH);
+        eprintln!("Warning: {}", warning);
+        report.warnings.push(warning);
+      }
+      depth
+    }
+  };
+  phase_start = report.phase("load_scene", phase_start);
+
+  if reporting {
+    report.input(obj_file);
+    let textures: HashSet<&String> = scene.materials.iter().filter_map(|m| m.texture.as_ref()).collect();
+    for texture in textures {
+      report.input(&scene_loader::texture_path(obj_file, texture));
+    }
+    if let Some(ref path) = args.attributes {
+      report.input(&Location::Local(path.clone()));
+    }
+  }
+  report.parameter("depth", depth as u64);
+  report.parameter("voxel_size", match args.resolution {
+    Resolution::VoxelSize(size) => Some(size as f64),
+    Resolution::Depth(_) => None,
+  });
+  report.parameter("step_level", step_level as u64);
+  report.parameter("output_name", output_name.to_string_lossy().as_ref());
+  report.parameter("no_cache", args.no_cache);
+  report.parameter("cache_limit_mb", args.cache_limit_mb);
+  report.parameter("attributes", args.attributes.as_ref().map(|p| p.to_string_lossy()).as_deref());
+  report.parameter("vertex_color_attributes", args.vertex_color_attributes);
+  report.parameter("split", args.split);
+  report.parameter("winding", match args.winding {
+    Winding::Keep => "keep",
+    Winding::Flip => "flip",
+    Winding::Auto => "auto",
+  });
+
+  let mut attributes = TriangleAttributes {
+    names: Vec::new(),
+    values: Vec::new(),
+  };
+  if let Some(ref path) = args.attributes {
+    match attributes::load(path, scene.triangles_indexed.len()) {
+      Ok(loaded) => attributes.append(loaded),
+      Err(e) => {
+        eprintln!("{}", e);
+        return;
+      }
+    }
+  }
+  if args.vertex_color_attributes {
+    match attributes::from_vertex_colors(&scene) {
+      Some(colors) => attributes.append(colors),
+      None => {
+        let warning = String::from("--vertex-color-attributes given but the model has no vertex colors.");
+        eprintln!("Warning: {}", warning);
+        report.warnings.push(warning);
+      }
+    }
+  }
+  phase_start = report.phase("load_attributes", phase_start);
+
+  let cache = if args.no_cache {
+    None
+  } else {
+    TextureCache::open(args.cache_limit_mb)
+  };
+
+  let config = BuildConfig::new(depth).with_step_level(step_level);
+  let c_scene = create_c_scene(&scene, obj_file, &scene.aabb, cache.as_ref()).unwrap_or_else(|e| {
+    eprintln!("Error voxelizing '{}': {}", obj_file, e);
+    std::process::exit(1);
+  });
+  let node_pool = NodePool::build(&c_scene, &config).unwrap_or_else(|e| {
+    eprintln!("Error voxelizing '{}': {}", obj_file, e);
+    std::process::exit(1);
+  });
+  phase_start = report.phase("voxelize", phase_start);
+  report.node_count = node_pool.len() as u64;
+
+  println!("Serializing pool: count = {},", node_pool.len());
+  let pool_path = cli::output_path(output_name, "svdag");
+  oasis_format::write_nodes(node_pool.as_slice(), &pool_path).expect("Failed to serialize node pool");
+  phase_start = report.phase("serialize", phase_start);
+  if reporting {
+    report.output(&pool_path);
+  }
+
+  let mut attribute_path = None;
+  if !attributes.is_empty() {
+    let baked = attributes::bake(node_pool.as_slice(), &scene, &scene.aabb.cube(), &attributes);
+    println!("Baked attribute channels: {}", baked.names.join(", "));
+    let path = cli::output_path(output_name, ATTRIBUTE_EXTENSION);
+    write_attributes(&baked, &path).expect("Failed to write attribute channels");
+    phase_start = report.phase("bake_attributes", phase_start);
+    if reporting {
+      report.output(&path);
+    }
+    attribute_path = Some(path);
+  }
+
+  if args.split {
+    split::write_split(node_pool.as_slice(), output_name, attribute_path.as_deref())
+      .expect("Failed to write split topology and color streams");
+    println!(
+      "Wrote split streams '{0}.{1}' and '{0}.{2}'.",
+      output_name.display(),
+      split::TOPOLOGY_EXTENSION,
+      split::COLOR_EXTENSION
+    );
+    report.phase("split", phase_start);
+    if reporting {
+      report.output(cli::output_path(output_name, split::TOPOLOGY_EXTENSION));
+      report.output(cli::output_path(output_name, split::COLOR_EXTENSION));
+    }
+  }
+
+  if let Some(ref path) = args.report {
+    // Loader warnings come first, they were raised first
+    report.warnings.splice(0..0, scene.warnings.iter().cloned());
+    match report.write(path) {
+      Ok(()) => println!("Wrote build report '{}'.", path.display()),
+      Err(e) => eprintln!("Error writing build report '{}': {}", path.display(), e),
+    }
+  }
+}
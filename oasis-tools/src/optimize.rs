@@ -0,0 +1,312 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::VecDeque;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use oasis_format::{read_nodes, validate_children, write_nodes, Node, NodeArena};
+
+use crate::cli;
+
+// Child pointers landing within this many nodes of their parent count as
+// "near" in the locality stats: 64 nodes of 48 bytes span a few KB, about
+// what a traversal step can hope to find already in cache.
+const NEAR_DISTANCE: usize = 64;
+
+// Morton codes interleave this many bits per axis. One more than the
+// deepest level the viewer traverses, so cell centers are exact.
+const MORTON_BITS: u32 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Order {
+  // Level by level, siblings next to each other
+  Bfs,
+  // Pre-order, each subtree contiguous
+  Dfs,
+  // Sorted by the Morton code of the cell center, so a parent lands between
+  // its children and nodes close in space are close in memory
+  Morton,
+}
+
+impl Order {
+  pub fn parse(value: &str) -> Option<Self> {
+    match value {
+      "bfs" => Some(Order::Bfs),
+      "dfs" => Some(Order::Dfs),
+      "morton" => Some(Order::Morton),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Locality {
+  pub pointers: usize,
+  // Mean and largest |child - parent| index distance
+  pub mean_distance: f64,
+  pub max_distance: usize,
+  // Share of pointers within NEAR_DISTANCE nodes of their parent
+  pub near: f64,
+}
+
+pub fn locality(nodes: &[Node]) -> Locality {
+  let mut pointers = 0;
+  let mut total = 0u64;
+  let mut max_distance = 0;
+  let mut near = 0;
+  for (index, node) in nodes.iter().enumerate() {
+    for slot in 0..8 {
+      if let Some(child) = node.child(slot) {
+        let distance = child.abs_diff(index);
+        pointers += 1;
+        total += distance as u64;
+        max_distance = max_distance.max(distance);
+        if distance <= NEAR_DISTANCE {
+          near += 1;
+        }
+      }
+    }
+  }
+
+  let share = |n: u64| if pointers == 0 { 0.0 } else { n as f64 / pointers as f64 };
+  Locality {
+    pointers,
+    mean_distance: share(total),
+    max_distance,
+    near: share(near),
+  }
+}
+
+fn bfs_order(nodes: &[Node]) -> Vec<usize> {
+  let mut seen = vec![false; nodes.len()];
+  let mut order = vec![0];
+  let mut queue = VecDeque::from([0]);
+  seen[0] = true;
+  while let Some(index) = queue.pop_front() {
+    for slot in 0..8 {
+      if let Some(child) = nodes[index].child(slot) {
+        if !seen[child] {
+          seen[child] = true;
+          order.push(child);
+          queue.push_back(child);
+        }
+      }
+    }
+  }
+  order
+}
+
+fn dfs_order(nodes: &[Node]) -> Vec<usize> {
+  let mut seen = vec![false; nodes.len()];
+  let mut order = Vec::new();
+  let mut stack = vec![0];
+  while let Some(index) = stack.pop() {
+    if seen[index] {
+      continue;
+    }
+    seen[index] = true;
+    order.push(index);
+    // Reversed so slot 0 is visited first
+    for slot in (0..8).rev() {
+      if let Some(child) = nodes[index].child(slot) {
+        if !seen[child] {
+          stack.push(child);
+        }
+      }
+    }
+  }
+  order
+}
+
+// Spreads the low MORTON_BITS bits of `v` to every third bit
+fn spread_bits(v: u32) -> u128 {
+  let mut out = 0u128;
+  for bit in 0..MORTON_BITS {
+    out |= (((v >> bit) & 1) as u128) << (3 * bit);
+  }
+  out
+}
+
+fn morton_order(nodes: &[Node]) -> Vec<usize> {
+  // Shared nodes occupy several cells; they are placed at the first (and so
+  // shallowest) one found breadth-first.
+  let mut keys: Vec<Option<u128>> = vec![None; nodes.len()];
+  let mut queue = VecDeque::from([(0usize, 0u32, [0u32; 3])]);
+  while let Some((index, level, min)) = queue.pop_front() {
+    if keys[index].is_some() {
+      continue;
+    }
+    let half = if level < MORTON_BITS { 1u32 << (MORTON_BITS - 1 - level) } else { 0 };
+    let center = min.map(|c| c + half);
+    keys[index] = Some(spread_bits(center[0]) | (spread_bits(center[1]) << 1) | (spread_bits(center[2]) << 2));
+
+    for slot in 0..8 {
+      if let Some(child) = nodes[index].child(slot) {
+        if keys[child].is_none() {
+          let mut child_min = min;
+          for (axis, c) in child_min.iter_mut().enumerate() {
+            if slot & (1 << axis) != 0 {
+              *c += half;
+            }
+          }
+          queue.push_back((child, level + 1, child_min));
+        }
+      }
+    }
+  }
+
+  // The viewer starts traversal at node 0, so the root stays first
+  let mut order: Vec<usize> = (1..nodes.len()).filter(|&i| keys[i].is_some()).collect();
+  order.sort_by_key(|&i| keys[i]);
+  order.insert(0, 0);
+  order
+}
+
+// Reorders the pool, remapping child pointers. The root stays at index 0 and
+// nodes unreachable from it are dropped.
+pub fn reorder(nodes: &[Node], order: Order) -> Result<Vec<Node>, String> {
+  if nodes.is_empty() {
+    return Err(String::from("Node pool is empty"));
+  }
+  validate_children(nodes).map_err(|e| e.to_string())?;
+
+  let permutation = match order {
+    Order::Bfs => bfs_order(nodes),
+    Order::Dfs => dfs_order(nodes),
+    Order::Morton => morton_order(nodes),
+  };
+
+  let mut arena = NodeArena::from_nodes(nodes);
+  arena.permute(&permutation)?;
+  Ok(arena.to_vec())
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./builder optimize <pool.svdag> --reorder <morton|bfs|dfs> [output_name]");
+  std::process::exit(1);
+}
+
+fn print_locality(label: &str, stats: &Locality) {
+  println!(
+    "  {:<7} mean child distance {:.1}, max {}, {:.1}% within {} nodes",
+    label,
+    stats.mean_distance,
+    stats.max_distance,
+    stats.near * 100.0,
+    NEAR_DISTANCE
+  );
+}
+
+pub fn run(mut args: impl Iterator<Item = OsString>) {
+  let mut order: Option<Order> = None;
+  let mut positional: Vec<OsString> = Vec::new();
+
+  while let Some(arg) = args.next() {
+    match cli::text(&arg).as_str() {
+      "--reorder" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        order = Some(Order::parse(&value).unwrap_or_else(|| {
+          eprintln!("Error: --reorder must be morton, bfs or dfs, got '{}'", value);
+          print_usage();
+        }));
+      }
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ => positional.push(arg),
+    }
+  }
+
+  let mut positional = positional.into_iter();
+  let input = PathBuf::from(positional.next().unwrap_or_else(|| print_usage()));
+  let output_name = positional.next().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("optimized"));
+  let order = order.unwrap_or_else(|| print_usage());
+
+  let nodes = match read_nodes(&input) {
+    Ok(nodes) => nodes,
+    Err(e) => {
+      eprintln!("Error reading node pool '{}': {}", input.display(), e);
+      std::process::exit(1);
+    }
+  };
+  println!("Loaded {} nodes from '{}'.", nodes.len(), input.display());
+
+  let reordered = match reorder(&nodes, order) {
+    Ok(reordered) => reordered,
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    }
+  };
+
+  let before = locality(&nodes);
+  println!("Locality of {} child pointers ({:?} order):", before.pointers, order);
+  print_locality("before", &before);
+  print_locality("after", &locality(&reordered));
+  if reordered.len() < nodes.len() {
+    println!("Dropped {} unreachable nodes.", nodes.len() - reordered.len());
+  }
+
+  write_nodes(&reordered, cli::output_path(&output_name, "svdag")).expect("Failed to serialize node pool");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::test_pools::{node, pool_from_cells, sample_pool, voxels};
+
+  const ORDERS: [Order; 3] = [Order::Bfs, Order::Dfs, Order::Morton];
+
+  // Colors every node differently so a misplaced node changes the decoded colors
+  fn colored_ball() -> Vec<Node> {
+    let mut nodes = pool_from_cells(4, &|[x, y, z]| {
+      let d = [x, y, z].map(|c| c as f32 - 7.5);
+      d[0] * d[0] + d[1] * d[1] + d[2] * d[2] < 40.0
+    });
+    for (i, node) in nodes.iter_mut().enumerate() {
+      node.yuv = [i as f32, (i % 7) as f32, -(i as f32), 0.0];
+    }
+    nodes
+  }
+
+  #[test]
+  fn reordered_pools_decode_the_same() {
+    for nodes in [sample_pool(), colored_ball()] {
+      let expected = voxels(&nodes, 4);
+      for order in ORDERS {
+        let reordered = reorder(&nodes, order).unwrap();
+        assert_eq!(reordered.len(), nodes.len(), "{:?}", order);
+        assert_eq!(reordered[0].yuv, nodes[0].yuv, "{:?} moved the root", order);
+        assert_eq!(voxels(&reordered, 4), expected, "{:?}", order);
+      }
+    }
+  }
+
+  #[test]
+  fn reorder_drops_unreachable_nodes() {
+    let mut nodes = sample_pool();
+    nodes.push(node([-1; 8], [1.0, 1.0, 1.0]));
+    for order in ORDERS {
+      let reordered = reorder(&nodes, order).unwrap();
+      assert_eq!(reordered.len(), 4, "{:?}", order);
+      assert_eq!(voxels(&reordered, 3), voxels(&nodes, 3), "{:?}", order);
+    }
+    assert!(reorder(&[], Order::Bfs).is_err());
+  }
+}
@@ -0,0 +1,293 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use image::{Rgba, RgbaImage};
+
+use oasis_format::{yuv_to_rgb, Node};
+
+// Voxel colors drawn for clustering. Enough to resolve clusters down to a
+// fraction of a percent without walking every voxel of a deep pool.
+const SAMPLE_COUNT: usize = 200_000;
+const MAX_ITERATIONS: usize = 50;
+const SWATCH_SIZE: u32 = 64;
+
+pub struct PaletteEntry {
+  pub rgb: [u8; 3],
+  // Fraction of the filled volume closest to this color
+  pub share: f32,
+}
+
+// Deterministic so repeated runs on the same pool print the same palette
+struct XorShift(u64);
+
+impl XorShift {
+  fn next(&mut self) -> u64 {
+    self.0 ^= self.0 << 13;
+    self.0 ^= self.0 >> 7;
+    self.0 ^= self.0 << 17;
+    self.0
+  }
+
+  // Uniform in [0, 1)
+  fn next_f32(&mut self) -> f32 {
+    (self.next() >> 40) as f32 / (1u64 << 24) as f32
+  }
+}
+
+// Filled fraction of each node's cube, resolved children first like the
+// heights in `pool_stats`
+fn filled_volumes(nodes: &[Node]) -> Vec<f32> {
+  let mut volumes: Vec<Option<f32>> = vec![None; nodes.len()];
+  let mut stack: Vec<usize> = if nodes.is_empty() { Vec::new() } else { vec![0] };
+  while let Some(&index) = stack.last() {
+    if volumes[index].is_some() {
+      stack.pop();
+      continue;
+    }
+    let mut pending = false;
+    let mut volume = 0.0;
+    for &child in &nodes[index].children {
+      if child < 0 {
+        volume += 0.125;
+      } else if child > 0 {
+        match volumes[(child - 1) as usize] {
+          Some(v) => volume += v * 0.125,
+          None => {
+            stack.push((child - 1) as usize);
+            pending = true;
+          }
+        }
+      }
+    }
+    if !pending {
+      volumes[index] = Some(volume);
+      stack.pop();
+    }
+  }
+  volumes.into_iter().map(|v| v.unwrap_or(0.0)).collect()
+}
+
+// Draws voxel colors with probability proportional to the volume they cover.
+// Colors depend on the path taken (the shader sums YUV on the way down), so
+// shared nodes can't be counted once; random descents sidestep that.
+fn sample_colors(nodes: &[Node], count: usize) -> Vec<[u8; 3]> {
+  let volumes = filled_volumes(nodes);
+  if !matches!(volumes.first(), Some(&v) if v > 0.0) {
+    return Vec::new();
+  }
+
+  let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+  let mut samples = Vec::with_capacity(count);
+  for _ in 0..count {
+    let mut node = 0usize;
+    let mut yuv = [0.0f32; 3];
+    loop {
+      let weight = |child: i32| match child {
+        c if c < 0 => 1.0,
+        c if c > 0 => volumes[(c - 1) as usize],
+        _ => 0.0,
+      };
+      let total: f32 = nodes[node].children.iter().map(|&c| weight(c)).sum();
+      let mut pick = rng.next_f32() * total;
+      let mut chosen = 0;
+      for (slot, &child) in nodes[node].children.iter().enumerate() {
+        let w = weight(child);
+        if w > 0.0 {
+          chosen = slot;
+          if pick < w {
+            break;
+          }
+          pick -= w;
+        }
+      }
+
+      let child = nodes[node].children[chosen];
+      if child < 0 {
+        samples.push(yuv_to_rgb(yuv));
+        break;
+      }
+      // Like frag.glsl, a node's color is only added when descending past it
+      for (sum, channel) in yuv.iter_mut().zip(nodes[node].yuv) {
+        *sum += channel;
+      }
+      node = (child - 1) as usize;
+    }
+  }
+  samples
+}
+
+fn srgb_to_lab(rgb: [u8; 3]) -> [f32; 3] {
+  let linear = rgb.map(|c| {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+      c / 12.92
+    } else {
+      ((c + 0.055) / 1.055).powf(2.4)
+    }
+  });
+
+  // sRGB to XYZ, relative to the D65 white point
+  let x = (0.4124 * linear[0] + 0.3576 * linear[1] + 0.1805 * linear[2]) / 0.95047;
+  let y = 0.2126 * linear[0] + 0.7152 * linear[1] + 0.0722 * linear[2];
+  let z = (0.0193 * linear[0] + 0.1192 * linear[1] + 0.9505 * linear[2]) / 1.08883;
+
+  let f = |t: f32| {
+    if t > 0.008856 {
+      t.cbrt()
+    } else {
+      7.787 * t + 16.0 / 116.0
+    }
+  };
+  let (fx, fy, fz) = (f(x), f(y), f(z));
+  [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+fn distance_sq(a: [f32; 3], b: [f32; 3]) -> f32 {
+  (0..3).map(|k| (a[k] - b[k]) * (a[k] - b[k])).sum()
+}
+
+fn nearest(centers: &[[f32; 3]], p: [f32; 3]) -> usize {
+  let mut best = 0;
+  for (i, &c) in centers.iter().enumerate() {
+    if distance_sq(c, p) < distance_sq(centers[best], p) {
+      best = i;
+    }
+  }
+  best
+}
+
+// Clusters the pool's voxel colors into at most `k` entries with k-means in
+// CIELAB, where distances roughly follow perceived color differences.
+// Entries are sorted by share, largest first.
+pub fn extract_palette(nodes: &[Node], k: usize) -> Vec<PaletteEntry> {
+  let samples = sample_colors(nodes, SAMPLE_COUNT);
+  if samples.is_empty() || k == 0 {
+    return Vec::new();
+  }
+  let points: Vec<[f32; 3]> = samples.iter().map(|&rgb| srgb_to_lab(rgb)).collect();
+
+  // k-means++ seeding spreads the initial centers over distinct colors
+  let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+  let mut centers = vec![points[(rng.next() % points.len() as u64) as usize]];
+  let mut closest: Vec<f32> = points.iter().map(|&p| distance_sq(p, centers[0])).collect();
+  while centers.len() < k {
+    let total: f32 = closest.iter().sum();
+    if total <= 0.0 {
+      // Fewer distinct colors than requested clusters
+      break;
+    }
+    let mut pick = rng.next_f32() * total;
+    let mut chosen = points.len() - 1;
+    for (i, &d) in closest.iter().enumerate() {
+      if pick < d {
+        chosen = i;
+        break;
+      }
+      pick -= d;
+    }
+    centers.push(points[chosen]);
+    for (i, &p) in points.iter().enumerate() {
+      closest[i] = closest[i].min(distance_sq(p, points[chosen]));
+    }
+  }
+
+  let mut assignment = vec![0usize; points.len()];
+  for _ in 0..MAX_ITERATIONS {
+    let mut changed = false;
+    for (i, &p) in points.iter().enumerate() {
+      let c = nearest(&centers, p);
+      if c != assignment[i] {
+        assignment[i] = c;
+        changed = true;
+      }
+    }
+
+    let mut sums = vec![[0.0f64; 3]; centers.len()];
+    let mut counts = vec![0usize; centers.len()];
+    for (i, &p) in points.iter().enumerate() {
+      for (sum, channel) in sums[assignment[i]].iter_mut().zip(p) {
+        *sum += channel as f64;
+      }
+      counts[assignment[i]] += 1;
+    }
+    for (c, center) in centers.iter_mut().enumerate() {
+      if counts[c] > 0 {
+        *center = sums[c].map(|s| (s / counts[c] as f64) as f32);
+      }
+    }
+
+    if !changed {
+      break;
+    }
+  }
+
+  // Report each cluster by the mean sRGB of its members, which avoids a
+  // round trip through Lab for colors that are already exact
+  let mut rgb_sums = vec![[0u64; 3]; centers.len()];
+  let mut counts = vec![0usize; centers.len()];
+  for (i, rgb) in samples.iter().enumerate() {
+    for (sum, &channel) in rgb_sums[assignment[i]].iter_mut().zip(rgb) {
+      *sum += channel as u64;
+    }
+    counts[assignment[i]] += 1;
+  }
+
+  let mut palette: Vec<PaletteEntry> = (0..centers.len())
+    .filter(|&c| counts[c] > 0)
+    .map(|c| PaletteEntry {
+      rgb: rgb_sums[c].map(|s| ((s as f64 / counts[c] as f64).round()) as u8),
+      share: counts[c] as f32 / samples.len() as f32,
+    })
+    .collect();
+  palette.sort_by(|a, b| b.share.total_cmp(&a.share));
+  palette
+}
+
+// One square swatch per entry, left to right
+pub fn render_palette(palette: &[PaletteEntry]) -> RgbaImage {
+  let width = SWATCH_SIZE * palette.len().max(1) as u32;
+  RgbaImage::from_fn(width, SWATCH_SIZE, |x, _| match palette.get((x / SWATCH_SIZE) as usize) {
+    Some(entry) => Rgba([entry.rgb[0], entry.rgb[1], entry.rgb[2], 255]),
+    None => Rgba([0, 0, 0, 0]),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::test_pools::{sample_pool, voxels};
+
+  #[test]
+  fn palette_matches_sampled_voxels() {
+    let nodes = sample_pool();
+    // Every voxel of the three level pool is a cell of the 8^3 grid
+    let colors: Vec<[u8; 3]> = voxels(&nodes, 3).into_iter().flatten().map(yuv_to_rgb).collect();
+    let mut distinct = colors.clone();
+    distinct.sort();
+    distinct.dedup();
+
+    let mut palette = extract_palette(&nodes, distinct.len());
+    palette.sort_by_key(|entry| entry.rgb);
+    let rgbs: Vec<[u8; 3]> = palette.iter().map(|entry| entry.rgb).collect();
+    assert_eq!(rgbs, distinct);
+
+    for entry in &palette {
+      let expected = colors.iter().filter(|&&c| c == entry.rgb).count() as f32 / colors.len() as f32;
+      assert!((entry.share - expected).abs() < 0.01, "{:?}: {} vs {}", entry.rgb, entry.share, expected);
+    }
+  }
+}
@@ -0,0 +1,380 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use oasis::{BuildConfig, NodePool};
+use oasis_format::{read_nodes, write_nodes, ArenaStats, Node, NodeArena};
+
+use crate::cli;
+use crate::{create_c_scene, MAX_DEPTH};
+use crate::extract::parse_region;
+use crate::scene_loader::{load_obj_scene, SceneOptions, Winding, AABB};
+use crate::texture_cache::{self, TextureCache};
+use crate::vfs::Location;
+
+// Octree cell addressed by level and integer coordinates at that level
+#[derive(Debug, Clone, Copy)]
+pub struct PatchCell {
+  pub level: u32,
+  pub coords: [u32; 3],
+}
+
+impl PatchCell {
+  // Child slot taken at each level on the way down from the root
+  pub fn slot_path(&self) -> Vec<usize> {
+    (0..self.level)
+      .map(|l| {
+        let shift = self.level - 1 - l;
+        let mut slot = 0usize;
+        for k in 0..3 {
+          if (self.coords[k] >> shift) & 1 == 1 {
+            slot |= 1 << k;
+          }
+        }
+        slot
+      })
+      .collect()
+  }
+
+  // Bounds of the cell in world space, given the cube the pool was built in
+  pub fn world_bounds(&self, cube: &AABB) -> AABB {
+    let size = (cube.max[0] - cube.min[0]) / (1u64 << self.level) as f32;
+    let mut bounds = AABB::default();
+    for k in 0..3 {
+      bounds.min[k] = cube.min[k] + self.coords[k] as f32 * size;
+      bounds.max[k] = bounds.min[k] + size;
+    }
+    bounds
+  }
+}
+
+// Deepest aligned cell (at most `max_level`) fully containing the normalized
+// region `[min, max]`.
+pub fn region_cell(min: [f32; 3], max: [f32; 3], max_level: u32) -> PatchCell {
+  for level in (0..=max_level).rev() {
+    let n = (1u64 << level) as f32;
+    let limit = (1u64 << level) as i64 - 1;
+
+    let mut coords = [0u32; 3];
+    let mut fits = true;
+    for k in 0..3 {
+      let lo = ((min[k] * n).floor() as i64).clamp(0, limit);
+      let hi = (((max[k] * n).ceil() as i64) - 1).clamp(lo, limit);
+      if lo != hi {
+        fits = false;
+        break;
+      }
+      coords[k] = lo as u32;
+    }
+
+    if fits {
+      return PatchCell { level, coords };
+    }
+  }
+
+  PatchCell {
+    level: 0,
+    coords: [0; 3],
+  }
+}
+
+// Replaces the cell reached through `path` with `subtree` (whose root covers
+// that cell), returning a compacted pool and the arena statistics from before
+// compaction. Nodes along the path are copied rather than edited in place
+// since DAG nodes may be shared by other cells.
+pub fn splice_subtree(nodes: &[Node], path: &[usize], subtree: &[Node]) -> Result<(Vec<Node>, ArenaStats), String> {
+  if nodes.is_empty() {
+    return Err(String::from("Node pool is empty"));
+  }
+  if path.is_empty() {
+    // Patching the whole pool is just a rebuild
+    return Ok((subtree.to_vec(), NodeArena::from_nodes(subtree).stats()));
+  }
+
+  let mut arena = NodeArena::from_nodes(nodes);
+
+  // Copy the root, then walk down copying (or creating) each node on the path
+  let new_root = arena.alloc(nodes[0]);
+  let mut current = new_root;
+  let mut ancestor_yuv = [0.0f32; 3];
+
+  for &slot in &path[..path.len() - 1] {
+    let node = *arena.get(current);
+    add_yuv(&mut ancestor_yuv, &node.yuv);
+
+    let next = match node.child(slot) {
+      Some(child) if arena.is_live(child) => *arena.get(child),
+      Some(child) => {
+        return Err(format!("Node {} points to node {}, past the end of the pool", current, child));
+      }
+      None => split_slot(&mut arena, &node, slot),
+    };
+
+    let copy = arena.alloc(next);
+    arena.get_mut(current).children[slot] = (copy + 1) as i32;
+    current = copy;
+  }
+  add_yuv(&mut ancestor_yuv, &arena.get(current).yuv);
+
+  let last_slot = path[path.len() - 1];
+  match arena.append(subtree)? {
+    None => arena.get_mut(current).children[last_slot] = 0,
+    Some(sub_root) => {
+      // Subtree colors accumulate on top of the ancestors' along the path
+      for (channel, ancestor) in arena.get_mut(sub_root).yuv.iter_mut().zip(ancestor_yuv) {
+        *channel -= ancestor;
+      }
+      arena.get_mut(current).children[last_slot] = (sub_root + 1) as i32;
+    }
+  }
+
+  // Free the nodes only the old path referenced and move the root back to 0
+  arena.collect(new_root)?;
+  let stats = arena.stats();
+  arena.compact(new_root)?;
+  Ok((arena.to_vec(), stats))
+}
+
+fn add_yuv(sum: &mut [f32; 3], yuv: &[f32; 4]) {
+  for (channel, value) in sum.iter_mut().zip(yuv) {
+    *channel += value;
+  }
+}
+
+// Node replacing the leaf or empty slot `slot` of `parent` so the path can
+// continue through it. A leaf only shows the colors of its node's ancestors,
+// so sibling leaves moved one level down would pick up `parent`'s color.
+// When that color is non-zero the new node cancels it and keeps the leaves
+// one level further down, under a shared all-leaf node.
+fn split_slot(arena: &mut NodeArena, parent: &Node, slot: usize) -> Node {
+  let leaf = parent.children[slot].min(0);
+  let parent_yuv = [parent.yuv[0], parent.yuv[1], parent.yuv[2]];
+  if leaf == 0 || parent_yuv == [0.0; 3] {
+    return Node {
+      children: [leaf; 8],
+      yuv: [0.0; 4],
+    };
+  }
+
+  let filler = arena.alloc(Node {
+    children: [leaf; 8],
+    yuv: [0.0; 4],
+  });
+  Node {
+    children: [(filler + 1) as i32; 8],
+    yuv: [-parent_yuv[0], -parent_yuv[1], -parent_yuv[2], 0.0],
+  }
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./builder patch <pool.svdag> <model.obj> <depth> <step_level> --region <x0,y0,z0,x1,y1,z1> [--bounds <x0,y0,z0,x1,y1,z1>] [--flip-winding | --auto-winding] [output_name]");
+  eprintln!("Regions are given in the pool's normalized [0, 1] space, <depth> must match the original build.");
+  eprintln!("--bounds overrides the world-space scene bounds if the edit changed them.");
+  eprintln!("Pass the same winding option as the original build.");
+  std::process::exit(1);
+}
+
+fn fail(message: &str) -> ! {
+  eprintln!("Error: {}", message);
+  print_usage();
+}
+
+pub fn run(mut args: impl Iterator<Item = OsString>) {
+  let mut region: Option<([f32; 3], [f32; 3])> = None;
+  let mut bounds: Option<AABB> = None;
+  let mut scene_options = SceneOptions::default();
+  let mut positional: Vec<OsString> = Vec::new();
+
+  while let Some(arg) = args.next() {
+    match cli::text(&arg).as_str() {
+      "--region" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        region = Some(parse_region(&value).unwrap_or_else(|| {
+          fail(&format!("--region must be x0,y0,z0,x1,y1,z1, got '{}'", value))
+        }));
+      }
+      "--bounds" => {
+        let value = cli::text(&args.next().unwrap_or_else(|| print_usage()));
+        let (min, max) = parse_region(&value).unwrap_or_else(|| {
+          fail(&format!("--bounds must be x0,y0,z0,x1,y1,z1, got '{}'", value))
+        });
+        bounds = Some(AABB { min, max });
+      }
+      "--flip-winding" => scene_options.winding = Winding::Flip,
+      "--auto-winding" => scene_options.winding = Winding::Auto,
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ => positional.push(arg),
+    }
+  }
+
+  let mut positional = positional.into_iter();
+  let pool_file = PathBuf::from(positional.next().unwrap_or_else(|| print_usage()));
+  let obj_file = Location::parse(&positional.next().unwrap_or_else(|| print_usage()));
+  let depth = cli::text(&positional.next().unwrap_or_else(|| print_usage()));
+  let depth = cli::parse_number("depth", &depth, 1, MAX_DEPTH).unwrap_or_else(|e| fail(&e));
+  let step_level = cli::text(&positional.next().unwrap_or_else(|| print_usage()));
+  let step_level = cli::parse_number("step_level", &step_level, 0, MAX_DEPTH).unwrap_or_else(|e| fail(&e));
+  let output_name = positional.next().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("patched"));
+  let (region_min, region_max) = region.unwrap_or_else(|| print_usage());
+
+  let nodes = match read_nodes(&pool_file) {
+    Ok(nodes) => nodes,
+    Err(e) => {
+      eprintln!("Error reading node pool '{}': {}", pool_file.display(), e);
+      std::process::exit(1);
+    }
+  };
+  println!("Loaded {} nodes from '{}'.", nodes.len(), pool_file.display());
+
+  let scene = match load_obj_scene(&obj_file, &scene_options) {
+    Ok(scene) => scene,
+    Err(e) => {
+      eprintln!("Error loading OBJ file: {}", e);
+      std::process::exit(1);
+    }
+  };
+
+  // The patched cell has to leave at least one level to rebuild
+  let cell = region_cell(region_min, region_max, depth.saturating_sub(1) as u32);
+  let cube = bounds.unwrap_or(scene.aabb).cube();
+  let cell_bounds = cell.world_bounds(&cube);
+  let sub_depth = depth - cell.level as u8;
+
+  println!(
+    "Rebuilding cell {:?} at level {} (world {:?} - {:?}) with depth {}.",
+    cell.coords, cell.level, cell_bounds.min, cell_bounds.max, sub_depth
+  );
+
+  let cache = TextureCache::open(texture_cache::DEFAULT_LIMIT_MB);
+  let config = BuildConfig::new(sub_depth).with_step_level(step_level);
+  let built = create_c_scene(&scene, &obj_file, &cell_bounds, cache.as_ref())
+    .and_then(|c_scene| NodePool::build(&c_scene, &config).map(|pool| pool.as_slice().to_vec()));
+  let subtree = match built {
+    Ok(subtree) => subtree,
+    Err(e) => {
+      eprintln!("Error voxelizing '{}': {}", obj_file, e);
+      std::process::exit(1);
+    }
+  };
+
+  let patched = match splice_subtree(&nodes, &cell.slot_path(), &subtree) {
+    Ok((patched, stats)) => {
+      println!("Arena before compaction: {}", stats);
+      patched
+    }
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    }
+  };
+
+  println!(
+    "Spliced {} new nodes, pool went from {} to {} nodes.",
+    subtree.len(),
+    nodes.len(),
+    patched.len()
+  );
+  write_nodes(&patched, cli::output_path(&output_name, "svdag")).expect("Failed to serialize node pool");
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::test_pools::{node, sample_pool, voxels};
+
+  // Two levels with colors on both, and no leaves in the root's own slots
+  // since those can't carry a color in a standalone pool
+  fn replacement() -> Vec<Node> {
+    vec![
+      node([2, 0, 0, 0, 0, 0, 0, 2], [10.0, 0.0, 0.0]),
+      node([-4, 0, 0, 0, 0, 0, 0, -4], [3.0, 0.0, 0.0]),
+    ]
+  }
+
+  // The source's 2^level grid with the cell at the end of `path` replaced by
+  // the standalone subtree's voxels
+  fn expected_cells(nodes: &[Node], path: &[usize], subtree: &[Node], level: u32) -> Vec<Option<[f32; 3]>> {
+    let mut grid = voxels(nodes, level);
+    let n = 1usize << level;
+    let sub_level = level - path.len() as u32;
+    let span = 1usize << sub_level;
+
+    let mut start = [0usize; 3];
+    for (depth, &slot) in path.iter().enumerate() {
+      for (k, coord) in start.iter_mut().enumerate() {
+        if slot & (1 << k) != 0 {
+          *coord += n >> (depth + 1);
+        }
+      }
+    }
+
+    let sub_grid = voxels(subtree, sub_level);
+    for z in 0..span {
+      for y in 0..span {
+        for x in 0..span {
+          grid[start[0] + x + n * (start[1] + y + n * (start[2] + z))] = sub_grid[x + span * (y + span * z)];
+        }
+      }
+    }
+    grid
+  }
+
+  fn check_splice(path: &[usize]) -> Vec<Node> {
+    let nodes = sample_pool();
+    let (patched, _) = splice_subtree(&nodes, path, &replacement()).unwrap();
+    oasis_format::validate_children(&patched).unwrap();
+    let level = path.len() as u32 + 2;
+    assert_eq!(voxels(&patched, level), expected_cells(&nodes, path, &replacement(), level));
+    patched
+  }
+
+  #[test]
+  fn splice_into_solid_leaf() {
+    // Node 1 is shared by root slots 0 and 4, slot 0 of it is a solid leaf
+    let patched = check_splice(&[0, 0, 3]);
+    // The copy is split, the other parent still points at the original
+    let nodes = sample_pool();
+    assert!(patched.len() > nodes.len());
+    assert_eq!(voxels(&patched, 1)[4], voxels(&nodes, 1)[4]);
+  }
+
+  #[test]
+  fn splice_into_empty_slot() {
+    check_splice(&[1, 0]);
+    check_splice(&[3, 5, 6]);
+  }
+
+  #[test]
+  fn splice_over_existing_node() {
+    // Node 3 goes away under node 1 but stays under node 2
+    let patched = check_splice(&[0, 3]);
+    assert!(patched.contains(&sample_pool()[3]));
+  }
+
+  #[test]
+  fn region_cells_follow_slot_bits() {
+    let cell = region_cell([0.3, 0.55, 0.8], [0.45, 0.7, 0.95], 4);
+    assert_eq!(cell.level, 2);
+    assert_eq!(cell.coords, [1, 2, 3]);
+    assert_eq!(cell.slot_path(), vec![6, 5]);
+  }
+}
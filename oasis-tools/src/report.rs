@@ -0,0 +1,343 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::hash::Fnv1a64;
+use crate::vfs::Location;
+
+// Bumped when fields are renamed or removed, adding fields keeps the version
+pub const REPORT_VERSION: u32 = 1;
+
+pub enum Value {
+  Null,
+  Bool(bool),
+  Number(f64),
+  String(String),
+}
+
+impl From<bool> for Value {
+  fn from(value: bool) -> Self {
+    Value::Bool(value)
+  }
+}
+
+impl From<f64> for Value {
+  fn from(value: f64) -> Self {
+    Value::Number(value)
+  }
+}
+
+impl From<u64> for Value {
+  fn from(value: u64) -> Self {
+    Value::Number(value as f64)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(value: &str) -> Self {
+    Value::String(value.to_string())
+  }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    value.map_or(Value::Null, Into::into)
+  }
+}
+
+pub struct FileDigest {
+  pub path: String,
+  pub bytes: u64,
+  // FNV-1a 64 of the contents, as 16 hex digits
+  pub fnv1a64: String,
+}
+
+impl FileDigest {
+  // Archive entries and URLs are read whole, local files are streamed
+  pub fn of_location(location: &Location) -> io::Result<Self> {
+    if let Location::Local(path) = location {
+      return Self::of(path);
+    }
+    let data = location.read()?;
+    let mut hasher = Fnv1a64::new();
+    hasher.update(&data);
+    Ok(Self {
+      path: location.to_string(),
+      bytes: data.len() as u64,
+      fnv1a64: format!("{:016x}", hasher.finish()),
+    })
+  }
+
+  pub fn of<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    let path = path.as_ref();
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut hasher = Fnv1a64::new();
+    let mut bytes = 0u64;
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+      let n = reader.read(&mut buf)?;
+      if n == 0 {
+        break;
+      }
+      hasher.update(&buf[..n]);
+      bytes += n as u64;
+    }
+
+    Ok(Self {
+      path: path.display().to_string(),
+      bytes,
+      fnv1a64: format!("{:016x}", hasher.finish()),
+    })
+  }
+}
+
+// Machine-readable summary of one build, written by --report
+#[derive(Default)]
+pub struct BuildReport {
+  pub inputs: Vec<FileDigest>,
+  pub parameters: Vec<(&'static str, Value)>,
+  pub node_count: u64,
+  // Wall time per phase in seconds, in execution order
+  pub phases: Vec<(&'static str, f64)>,
+  pub warnings: Vec<String>,
+  pub outputs: Vec<FileDigest>,
+}
+
+impl BuildReport {
+  pub fn parameter(&mut self, name: &'static str, value: impl Into<Value>) {
+    self.parameters.push((name, value.into()));
+  }
+
+  // Hashes `location` into `inputs`, unreadable files become warnings
+  pub fn input(&mut self, location: &Location) {
+    match FileDigest::of_location(location) {
+      Ok(digest) => self.inputs.push(digest),
+      Err(e) => self.warnings.push(format!("can't hash input '{}': {}", location, e)),
+    }
+  }
+
+  pub fn output<P: AsRef<Path>>(&mut self, path: P) {
+    match FileDigest::of(&path) {
+      Ok(digest) => self.outputs.push(digest),
+      Err(e) => self.warnings.push(format!("can't hash output '{}': {}", path.as_ref().display(), e)),
+    }
+  }
+
+  // Records the time since `start` as `phase` and returns a new start
+  pub fn phase(&mut self, phase: &'static str, start: Instant) -> Instant {
+    self.phases.push((phase, start.elapsed().as_secs_f64()));
+    Instant::now()
+  }
+
+  pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    fs::write(path, self.to_json())
+  }
+
+  pub fn to_json(&self) -> String {
+    let mut out = String::new();
+    let total: f64 = self.phases.iter().map(|(_, t)| t).sum();
+
+    out.push_str("{\n");
+    let _ = writeln!(out, "  \"version\": {},", REPORT_VERSION);
+
+    out.push_str("  \"inputs\": ");
+    write_digests(&mut out, &self.inputs);
+    out.push_str(",\n");
+
+    out.push_str("  \"parameters\": {");
+    for (i, (name, value)) in self.parameters.iter().enumerate() {
+      out.push_str(if i == 0 { "\n" } else { ",\n" });
+      let _ = write!(out, "    {}: ", json_string(name));
+      write_value(&mut out, value);
+    }
+    out.push_str(if self.parameters.is_empty() { "},\n" } else { "\n  },\n" });
+
+    let _ = writeln!(out, "  \"node_count\": {},", self.node_count);
+
+    out.push_str("  \"phases\": {");
+    for (i, (name, seconds)) in self.phases.iter().enumerate() {
+      out.push_str(if i == 0 { "\n" } else { ",\n" });
+      let _ = write!(out, "    {}: {:.6}", json_string(name), seconds);
+    }
+    out.push_str(if self.phases.is_empty() { "},\n" } else { "\n  },\n" });
+    let _ = writeln!(out, "  \"total_seconds\": {:.6},", total);
+
+    out.push_str("  \"warnings\": [");
+    for (i, warning) in self.warnings.iter().enumerate() {
+      out.push_str(if i == 0 { "\n    " } else { ",\n    " });
+      out.push_str(&json_string(warning));
+    }
+    out.push_str(if self.warnings.is_empty() { "],\n" } else { "\n  ],\n" });
+
+    out.push_str("  \"outputs\": ");
+    write_digests(&mut out, &self.outputs);
+    out.push_str("\n}\n");
+    out
+  }
+}
+
+fn write_digests(out: &mut String, digests: &[FileDigest]) {
+  if digests.is_empty() {
+    out.push_str("[]");
+    return;
+  }
+  out.push('[');
+  for (i, digest) in digests.iter().enumerate() {
+    out.push_str(if i == 0 { "\n" } else { ",\n" });
+    let _ = write!(
+      out,
+      "    {{ \"path\": {}, \"bytes\": {}, \"fnv1a64\": \"{}\" }}",
+      json_string(&digest.path),
+      digest.bytes,
+      digest.fnv1a64
+    );
+  }
+  out.push_str("\n  ]");
+}
+
+fn write_value(out: &mut String, value: &Value) {
+  match value {
+    Value::Null => out.push_str("null"),
+    Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+    // JSON has no NaN or infinity
+    Value::Number(n) if !n.is_finite() => out.push_str("null"),
+    Value::Number(n) => {
+      let _ = write!(out, "{}", n);
+    }
+    Value::String(s) => out.push_str(&json_string(s)),
+  }
+}
+
+fn json_string(s: &str) -> String {
+  let mut out = String::with_capacity(s.len() + 2);
+  out.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => {
+        let _ = write!(out, "\\u{:04x}", c as u32);
+      }
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use serde_json::json;
+
+  fn parse(report: &BuildReport) -> serde_json::Value {
+    let text = report.to_json();
+    serde_json::from_str(&text).unwrap_or_else(|e| panic!("invalid JSON ({}):\n{}", e, text))
+  }
+
+  #[test]
+  fn empty_report_is_valid_json() {
+    let value = parse(&BuildReport::default());
+    assert_eq!(
+      value,
+      json!({
+        "version": REPORT_VERSION,
+        "inputs": [],
+        "parameters": {},
+        "node_count": 0,
+        "phases": {},
+        "total_seconds": 0.0,
+        "warnings": [],
+        "outputs": [],
+      })
+    );
+  }
+
+  #[test]
+  fn filled_report_round_trips() {
+    let mut report = BuildReport {
+      node_count: 42,
+      phases: vec![("load", 0.5), ("build", 1.25)],
+      warnings: vec![String::from("first"), String::from("second")],
+      ..Default::default()
+    };
+    report.parameter("depth", 10u64);
+    report.parameter("voxel_size", 0.25);
+    report.parameter("flip_winding", true);
+    report.parameter("cache", None::<&str>);
+    report.parameter("name", "scene");
+    report.outputs.push(FileDigest {
+      path: String::from("out.svdag"),
+      bytes: 96,
+      fnv1a64: String::from("0123456789abcdef"),
+    });
+
+    let value = parse(&report);
+    assert_eq!(
+      value["parameters"],
+      json!({ "depth": 10, "voxel_size": 0.25, "flip_winding": true, "cache": null, "name": "scene" })
+    );
+    assert_eq!(value["node_count"], 42);
+    assert_eq!(value["phases"], json!({ "load": 0.5, "build": 1.25 }));
+    assert_eq!(value["total_seconds"], 1.75);
+    assert_eq!(value["warnings"], json!(["first", "second"]));
+    assert_eq!(
+      value["outputs"],
+      json!([{ "path": "out.svdag", "bytes": 96, "fnv1a64": "0123456789abcdef" }])
+    );
+  }
+
+  #[test]
+  fn strings_are_escaped() {
+    let nasty = "quote \" backslash \\ newline \n tab \t bell \u{7} unit \u{1f} é";
+    assert_eq!(json_string("a\"b\u{1}"), "\"a\\\"b\\u0001\"");
+
+    let mut report = BuildReport::default();
+    report.parameter("path", nasty);
+    report.warnings.push(String::from(nasty));
+    report.outputs.push(FileDigest {
+      path: String::from(nasty),
+      bytes: 0,
+      fnv1a64: String::from("0000000000000000"),
+    });
+
+    let value = parse(&report);
+    assert_eq!(value["parameters"]["path"], nasty);
+    assert_eq!(value["warnings"][0], nasty);
+    assert_eq!(value["outputs"][0]["path"], nasty);
+  }
+
+  #[test]
+  fn non_finite_numbers_become_null() {
+    let mut report = BuildReport::default();
+    report.parameter("nan", f64::NAN);
+    report.parameter("inf", f64::INFINITY);
+    report.parameter("neg_inf", f64::NEG_INFINITY);
+    report.parameter("tiny", 1e-300);
+
+    let value = parse(&report);
+    assert_eq!(value["parameters"], json!({ "nan": null, "inf": null, "neg_inf": null, "tiny": 1e-300 }));
+  }
+}
@@ -0,0 +1,728 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::HashMap;
+use std::io::BufReader;
+
+use crate::vfs::Location;
+
+#[derive(Default)]
+pub struct Scene {
+  pub materials: Vec<Material>,
+  pub vertices: Vec<[f32; 3]>,
+  pub texture_coords: Vec<[f32; 2]>,
+  pub triangles: Vec<[f32; 3]>,
+  pub triangles_indexed: Vec<TriIndexed>,
+  // Per-vertex colors parallel to `vertices`, empty if no model has any
+  pub vertex_colors: Vec<[f32; 3]>,
+  pub aabb: AABB,
+  // Warnings raised while loading, already printed, kept for build reports
+  pub warnings: Vec<String>,
+}
+
+impl Scene {
+  fn warn(&mut self, message: String) {
+    eprintln!("Warning: {}", message);
+    self.warnings.push(message);
+  }
+}
+
+#[derive(Default, Clone)]
+pub struct Material {
+  pub name: String,
+  pub texture: Option<String>,
+  pub diffuse: [f32; 3],
+  pub specular: [f32; 3],
+  pub ambient: [f32; 3],
+  pub exponent: f32,
+}
+
+// Handed to the C scene as is
+pub use oasis::TriangleIndexed as TriIndexed;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Default, Clone, Copy, Debug)]
+pub struct AABB {
+  pub min: [f32; 3],
+  pub max: [f32; 3],
+}
+
+impl AABB {
+  pub fn size(&self) -> [f32; 3] {
+    [
+      (self.max[0] - self.min[0]).max(0.0),
+      (self.max[1] - self.min[1]).max(0.0),
+      (self.max[2] - self.min[2]).max(0.0),
+    ]
+  }
+
+  // Edge length of the cube the scene is voxelized into
+  pub fn max_extent(&self) -> f32 {
+    let size = self.size();
+    size[0].max(size[1]).max(size[2])
+  }
+
+  // Cube the scene is voxelized into, anchored at `min`
+  pub fn cube(&self) -> AABB {
+    let extent = self.max_extent();
+    AABB {
+      min: self.min,
+      max: [self.min[0] + extent, self.min[1] + extent, self.min[2] + extent],
+    }
+  }
+
+  // Smallest octree depth whose voxels are at most `voxel_size` wide,
+  // clamped to `[1, max_depth]`.
+  pub fn depth_for_voxel_size(&self, voxel_size: f32, max_depth: u8) -> u8 {
+    let extent = self.max_extent();
+    if extent <= 0.0 || voxel_size <= 0.0 {
+      return 1;
+    }
+    let depth = (extent / voxel_size).log2().ceil();
+    depth.clamp(1.0, max_depth as f32) as u8
+  }
+
+  // Number of voxels along each axis at the given depth
+  pub fn grid_dimensions(&self, depth: u8) -> [u64; 3] {
+    let voxel = self.max_extent() / (1u64 << depth) as f32;
+    let size = self.size();
+    let dim = |s: f32| if voxel > 0.0 { ((s / voxel).ceil() as u64).max(1) } else { 1 };
+    [dim(size[0]), dim(size[1]), dim(size[2])]
+  }
+}
+
+// What to do about triangle winding after loading
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Winding {
+  // Use the file's winding, but warn about closed meshes that look inside-out
+  #[default]
+  Keep,
+  // Reverse every triangle
+  Flip,
+  // Reverse closed meshes with negative signed volume, leave open ones alone
+  Auto,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SceneOptions {
+  pub winding: Winding,
+}
+
+fn flip_triangle(tri: &mut TriIndexed) {
+  tri.v_idx.swap(1, 2);
+  tri.tc_idx.swap(1, 2);
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+  while parent[i] != i {
+    parent[i] = parent[parent[i]];
+    i = parent[i];
+  }
+  i
+}
+
+// Triangles grouped into edge-connected meshes. Vertices are welded by
+// position first, since tobj splits an object into one model per material
+// and each model gets its own copy of the shared vertices.
+fn connected_meshes(scene: &Scene) -> (Vec<usize>, Vec<Vec<usize>>) {
+  let mut welded_ids: HashMap<[u32; 3], usize> = HashMap::new();
+  let welded: Vec<usize> = scene
+    .vertices
+    .iter()
+    .map(|p| {
+      let key = [p[0].to_bits(), p[1].to_bits(), p[2].to_bits()];
+      let next = welded_ids.len();
+      *welded_ids.entry(key).or_insert(next)
+    })
+    .collect();
+
+  let mut parent: Vec<usize> = (0..welded_ids.len()).collect();
+  for tri in &scene.triangles_indexed {
+    let a = find(&mut parent, welded[tri.v_idx[0]]);
+    for &v in &tri.v_idx[1..] {
+      let b = find(&mut parent, welded[v]);
+      parent[b] = a;
+    }
+  }
+
+  let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+  for (t, tri) in scene.triangles_indexed.iter().enumerate() {
+    let root = find(&mut parent, welded[tri.v_idx[0]]);
+    groups.entry(root).or_default().push(t);
+  }
+  (welded, groups.into_values().collect())
+}
+
+// Signed volume of a mesh if it is closed and consistently wound, i.e. every
+// edge is shared by exactly two triangles traversing it in opposite directions.
+fn closed_mesh_volume(scene: &Scene, welded: &[usize], triangles: &[usize]) -> Option<f64> {
+  // Per undirected edge: (uses, +1/-1 per direction)
+  let mut edges: HashMap<(usize, usize), (u32, i32)> = HashMap::new();
+  let mut volume = 0.0f64;
+
+  for &t in triangles {
+    let v = scene.triangles_indexed[t].v_idx;
+    let ids = [welded[v[0]], welded[v[1]], welded[v[2]]];
+    if ids[0] == ids[1] || ids[1] == ids[2] || ids[2] == ids[0] {
+      // Degenerate, has no area or orientation
+      continue;
+    }
+
+    for j in 0..3 {
+      let (a, b) = (ids[j], ids[(j + 1) % 3]);
+      let edge = edges.entry((a.min(b), a.max(b))).or_insert((0, 0));
+      edge.0 += 1;
+      edge.1 += if a < b { 1 } else { -1 };
+    }
+
+    let p = v.map(|i| scene.vertices[i].map(|c| c as f64));
+    let cross = [
+      p[1][1] * p[2][2] - p[1][2] * p[2][1],
+      p[1][2] * p[2][0] - p[1][0] * p[2][2],
+      p[1][0] * p[2][1] - p[1][1] * p[2][0],
+    ];
+    volume += (p[0][0] * cross[0] + p[0][1] * cross[1] + p[0][2] * cross[2]) / 6.0;
+  }
+
+  let closed = !edges.is_empty() && edges.values().all(|&(uses, direction)| uses == 2 && direction == 0);
+  closed.then_some(volume)
+}
+
+// Applies `winding` and warns about closed meshes left inside-out
+fn fix_winding(scene: &mut Scene, winding: Winding) {
+  if winding == Winding::Flip {
+    scene.triangles_indexed.iter_mut().for_each(flip_triangle);
+  }
+
+  let (welded, meshes) = connected_meshes(scene);
+  let mut inverted = 0usize;
+  let mut closed = 0usize;
+  for triangles in &meshes {
+    if let Some(volume) = closed_mesh_volume(scene, &welded, triangles) {
+      closed += 1;
+      if volume < 0.0 {
+        inverted += 1;
+        if winding == Winding::Auto {
+          for &t in triangles {
+            flip_triangle(&mut scene.triangles_indexed[t]);
+          }
+        }
+      }
+    }
+  }
+
+  if winding == Winding::Auto {
+    println!(
+      "Winding: flipped {} of {} closed meshes, {} open meshes left as-is.",
+      inverted,
+      closed,
+      meshes.len() - closed
+    );
+  } else if inverted > 0 {
+    scene.warn(format!(
+      "{} of {} closed meshes are wound inside-out (negative volume), try --auto-winding or --flip-winding.",
+      inverted, closed
+    ));
+  }
+
+  // Keep the unindexed copy in sync
+  for (t, tri) in scene.triangles_indexed.iter().enumerate() {
+    for j in 0..3 {
+      scene.triangles[3 * t + j] = scene.vertices[tri.v_idx[j]];
+    }
+  }
+}
+
+// Resolves a texture named in the material library, relative to the OBJ.
+// MTL files written on Windows often use backslash separators, which other
+// platforms would read as part of the file name.
+pub fn texture_path(obj: &Location, texture: &str) -> Location {
+  let path = obj.sibling(texture);
+  if cfg!(not(windows)) && texture.contains('\\') && !path.exists() {
+    return obj.sibling(&texture.replace('\\', "/"));
+  }
+  path
+}
+
+// Loads an OBJ from any backend, its MTL libraries are read from next to it
+pub fn load_obj_scene(location: &Location, options: &SceneOptions) -> Result<Scene, String> {
+  let data = location.read().map_err(|e| format!("Failed to load OBJ file '{}': {}", location, e))?;
+  let (models, materials) = tobj::load_obj_buf(
+    &mut BufReader::new(&data[..]),
+    &tobj::LoadOptions {
+      triangulate: true,
+      ..Default::default()
+    },
+    |mtl_path| {
+      let mtl = location.sibling(&mtl_path.to_string_lossy());
+      let data = mtl.read().map_err(|_| tobj::LoadError::OpenFileFailed)?;
+      tobj::load_mtl_buf(&mut BufReader::new(&data[..]))
+    },
+  )
+  .map_err(|e| format!("Failed to load OBJ file: {e}"))?;
+
+  let mut scene = Scene::default();
+
+  scene.aabb.min = [f32::MAX; 3];
+  scene.aabb.max = [f32::MIN; 3];
+
+  let materials = match materials {
+    Ok(materials) => materials,
+    Err(e) => {
+      scene.warn(format!("failed to load materials: {e}"));
+      Vec::new()
+    }
+  };
+
+  // Names are handed to the C API as C strings
+  for m in &materials {
+    let names = [Some(&m.name), m.diffuse_texture.as_ref()];
+    if let Some(name) = names.into_iter().flatten().find(|name| name.contains('\0')) {
+      return Err(format!("Material name '{}' contains a NUL byte", name.escape_debug()));
+    }
+  }
+
+  // Keep tobj's material order, `mesh.material_id` indexes into it
+  scene.materials = materials
+    .iter()
+    .map(|m| Material {
+      name: m.name.clone(),
+      texture: m.diffuse_texture.clone(),
+      diffuse: m.diffuse.unwrap_or([0.0; 3]),
+      specular: m.specular.unwrap_or([0.0; 3]),
+      ambient: m.ambient.unwrap_or([0.0; 3]),
+      exponent: m.shininess.unwrap_or(0.0),
+    })
+    .collect();
+
+  add_models(&mut scene, &models, materials.len())?;
+
+  // An empty scene would leave the AABB inverted and voxelize to nothing
+  if scene.triangles_indexed.is_empty() {
+    return Err(format!("OBJ file '{}' contains no triangles", location));
+  }
+
+  fix_winding(&mut scene, options.winding);
+
+  Ok(scene)
+}
+
+// Appends tobj's models to the scene. `material_count` is the number of
+// materials loaded from the MTL files, material ids past it use the default.
+fn add_models(scene: &mut Scene, models: &[tobj::Model], material_count: usize) -> Result<(), String> {
+  // Faces without a (valid) material all share one default material
+  let mut default_material: Option<usize> = None;
+
+  for model in models {
+    let mesh = &model.mesh;
+
+    // Positions and texture coordinates are indexed independently, so both
+    // arrays are appended as-is and each triangle corner keeps its own pair
+    // of indices into them.
+    let v_offset = scene.vertices.len();
+    for pos in mesh.positions.chunks_exact(3) {
+      let pos = [pos[0], pos[1], pos[2]];
+      for (k, &p) in pos.iter().enumerate() {
+        scene.aabb.min[k] = scene.aabb.min[k].min(p);
+        scene.aabb.max[k] = scene.aabb.max[k].max(p);
+      }
+      scene.vertices.push(pos);
+    }
+    let v_count = scene.vertices.len() - v_offset;
+
+    if mesh.vertex_color.len() == mesh.positions.len() && !mesh.vertex_color.is_empty() {
+      // Models loaded before the first colored one default to white
+      scene.vertex_colors.resize(v_offset, [1.0; 3]);
+      for c in mesh.vertex_color.chunks_exact(3) {
+        scene.vertex_colors.push([c[0], c[1], c[2]]);
+      }
+    } else if !scene.vertex_colors.is_empty() {
+      scene.vertex_colors.resize(scene.vertices.len(), [1.0; 3]);
+    }
+
+    let tc_offset = scene.texture_coords.len();
+    for uv in mesh.texcoords.chunks_exact(2) {
+      scene.texture_coords.push([uv[0], uv[1]]);
+    }
+    let tc_count = scene.texture_coords.len() - tc_offset;
+
+    // Corners without a usable texture coordinate share a single placeholder
+    let mut placeholder_tc: Option<usize> = None;
+    let mut missing_texcoords = 0usize;
+
+    // tobj starts a new model whenever `usemtl` switches material, so each
+    // model is one face range with a single material.
+    let mat_idx = match mesh.material_id {
+      Some(id) if id < material_count => id,
+      _ => *default_material.get_or_insert_with(|| {
+        scene.materials.push(Material {
+          name: String::from("default"),
+          diffuse: [0.8; 3],
+          ..Default::default()
+        });
+        scene.materials.len() - 1
+      }),
+    };
+
+    for (face, corners) in mesh.indices.chunks_exact(3).enumerate() {
+      let mut v_idx = [0usize; 3];
+      let mut tc_idx = [0usize; 3];
+
+      for j in 0..3 {
+        let corner = 3 * face + j;
+
+        let pos_idx = corners[j] as usize;
+        if pos_idx >= v_count {
+          return Err(format!(
+            "Model '{}' face {} references vertex {} but only {} vertices exist",
+            model.name, face, pos_idx, v_count
+          ));
+        }
+        v_idx[j] = v_offset + pos_idx;
+
+        // tobj leaves `texcoord_indices` empty when texture coordinates share
+        // the position indices, otherwise it is parallel to `indices`.
+        let tex_idx = if tc_count == 0 {
+          None
+        } else if mesh.texcoord_indices.is_empty() {
+          Some(pos_idx)
+        } else {
+          mesh.texcoord_indices.get(corner).map(|&ti| ti as usize)
+        };
+
+        tc_idx[j] = match tex_idx {
+          Some(ti) if ti < tc_count => tc_offset + ti,
+          _ => {
+            if tc_count > 0 {
+              missing_texcoords += 1;
+            }
+            *placeholder_tc.get_or_insert_with(|| {
+              scene.texture_coords.push([0.0, 0.0]);
+              scene.texture_coords.len() - 1
+            })
+          }
+        };
+      }
+
+      scene.triangles_indexed.push(TriIndexed {
+        v_idx,
+        tc_idx,
+        mat_idx,
+      });
+
+      scene.triangles.push(scene.vertices[v_idx[0]]);
+      scene.triangles.push(scene.vertices[v_idx[1]]);
+      scene.triangles.push(scene.vertices[v_idx[2]]);
+    }
+
+    if missing_texcoords > 0 {
+      scene.warn(format!(
+        "model '{}' has {} face corners with missing or out of range texture coordinates, using (0, 0).",
+        model.name, missing_texcoords
+      ));
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use std::fs;
+  use std::path::{Path, PathBuf};
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use proptest::prelude::*;
+
+  fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+  }
+
+  fn load_with(name: &str, options: &SceneOptions) -> Result<Scene, String> {
+    load_obj_scene(&Location::Local(fixture(name)), options)
+  }
+
+  fn load(name: &str) -> Scene {
+    load_with(name, &SceneOptions::default()).unwrap()
+  }
+
+  // Invariants every loaded scene must hold
+  fn check_scene(scene: &Scene) {
+    assert_eq!(scene.triangles.len(), 3 * scene.triangles_indexed.len());
+    for (t, tri) in scene.triangles_indexed.iter().enumerate() {
+      assert!(tri.mat_idx < scene.materials.len());
+      for j in 0..3 {
+        assert!(tri.v_idx[j] < scene.vertices.len());
+        assert!(tri.tc_idx[j] < scene.texture_coords.len());
+        assert_eq!(scene.triangles[3 * t + j], scene.vertices[tri.v_idx[j]]);
+      }
+    }
+    for v in &scene.vertices {
+      for (k, &c) in v.iter().enumerate() {
+        assert!(scene.aabb.min[k] <= c && c <= scene.aabb.max[k]);
+      }
+    }
+  }
+
+  fn closed_volumes(scene: &Scene) -> Vec<f64> {
+    let (welded, meshes) = connected_meshes(scene);
+    meshes
+      .iter()
+      .filter_map(|triangles| closed_mesh_volume(scene, &welded, triangles))
+      .collect()
+  }
+
+  #[test]
+  fn cube_without_texcoords() {
+    let scene = load("cube.obj");
+    check_scene(&scene);
+    assert_eq!(scene.triangles_indexed.len(), 12);
+    assert_eq!(scene.aabb.min, [0.0; 3]);
+    assert_eq!(scene.aabb.max, [1.0; 3]);
+    // Every corner shares the single (0, 0) placeholder, without a warning
+    assert_eq!(scene.texture_coords, vec![[0.0, 0.0]]);
+    assert!(scene.warnings.is_empty());
+    assert_eq!(closed_volumes(&scene).len(), 1);
+    assert!((closed_volumes(&scene)[0] - 1.0).abs() < 1e-6);
+  }
+
+  #[test]
+  fn negative_indices_match_absolute() {
+    let absolute = load("cube.obj");
+    let relative = load("negative_indices.obj");
+    check_scene(&relative);
+    assert_eq!(absolute.triangles, relative.triangles);
+  }
+
+  #[test]
+  fn crlf_line_endings() {
+    let scene = load("crlf.obj");
+    check_scene(&scene);
+    assert_eq!(scene.triangles, load("cube.obj").triangles);
+    assert_eq!(scene.texture_coords.len(), 3);
+    assert!(scene.warnings.is_empty());
+  }
+
+  #[test]
+  fn separate_texcoord_indices() {
+    let scene = load("mixed_texcoords.obj");
+    check_scene(&scene);
+    assert!(scene.warnings.is_empty());
+
+    // (position, texture coordinate) of every corner, in file order
+    let corners: Vec<([f32; 3], [f32; 2])> = scene
+      .triangles_indexed
+      .iter()
+      .flat_map(|tri| (0..3).map(|j| (scene.vertices[tri.v_idx[j]], scene.texture_coords[tri.tc_idx[j]])))
+      .collect();
+    let expected = [
+      ([0.0, 0.0, 0.0], [0.0, 0.0]),
+      ([1.0, 0.0, 0.0], [1.0, 0.0]),
+      ([1.0, 1.0, 0.0], [1.0, 1.0]),
+      ([0.0, 0.0, 0.0], [0.0, 0.0]),
+      ([1.0, 1.0, 0.0], [1.0, 1.0]),
+      ([0.0, 1.0, 0.0], [0.0, 1.0]),
+      ([1.0, 0.0, 0.0], [0.0, 1.0]),
+      ([1.0, 1.0, 0.0], [0.0, 0.0]),
+      ([0.0, 1.0, 0.0], [1.0, 0.0]),
+    ];
+    assert_eq!(corners, expected);
+  }
+
+  #[test]
+  fn missing_texcoords_use_placeholder() {
+    // tobj never emits these itself, it reuses the previous index for corners
+    // without a `vt`, so build the mesh by hand
+    let model = tobj::Model::new(
+      tobj::Mesh {
+        positions: vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+        indices: vec![0, 1, 2, 0, 2, 1],
+        texcoords: vec![0.5, 0.25],
+        texcoord_indices: vec![0, 7, 0, 0],
+        ..Default::default()
+      },
+      String::from("partial"),
+    );
+
+    let mut scene = Scene::default();
+    scene.aabb.min = [f32::MAX; 3];
+    scene.aabb.max = [f32::MIN; 3];
+    add_models(&mut scene, &[model], 0).unwrap();
+    check_scene(&scene);
+
+    // One out of range index and two corners past the end of the indices
+    assert_eq!(scene.texture_coords, vec![[0.5, 0.25], [0.0, 0.0]]);
+    let tc: Vec<[usize; 3]> = scene.triangles_indexed.iter().map(|tri| tri.tc_idx).collect();
+    assert_eq!(tc, vec![[0, 1, 0], [0, 1, 1]]);
+    assert_eq!(scene.warnings.len(), 1);
+    assert!(scene.warnings[0].contains("'partial' has 3 face corners"));
+  }
+
+  #[test]
+  fn missing_mtl_falls_back_to_default_material() {
+    let scene = load("missing_mtl.obj");
+    check_scene(&scene);
+    assert_eq!(scene.materials.len(), 1);
+    assert_eq!(scene.materials[0].name, "default");
+    assert!(scene.warnings.iter().any(|w| w.contains("materials")));
+  }
+
+  #[test]
+  fn usemtl_groups_keep_mtl_order() {
+    let scene = load("multi_material.obj");
+    check_scene(&scene);
+
+    // MTL declaration order, then the default for faces without a material
+    let names: Vec<&str> = scene.materials.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["blue", "red", "green", "default"]);
+    assert_eq!(scene.materials[0].diffuse, [0.0, 0.0, 1.0]);
+    assert_eq!(scene.materials[1].diffuse, [1.0, 0.0, 0.0]);
+    assert_eq!(scene.materials[2].diffuse, [0.0, 1.0, 0.0]);
+
+    // Triangles come out grouped by model, match them up by their corners
+    let material_of = |corners: [[f32; 3]; 3]| {
+      let t = scene
+        .triangles_indexed
+        .iter()
+        .position(|tri| tri.v_idx.map(|v| scene.vertices[v]) == corners)
+        .unwrap();
+      scene.materials[scene.triangles_indexed[t].mat_idx].name.as_str()
+    };
+    let v = |x: f32, y: f32| [x, y, 0.0];
+    assert_eq!(material_of([v(0.0, 0.0), v(1.0, 0.0), v(0.0, 1.0)]), "default");
+    assert_eq!(material_of([v(1.0, 0.0), v(1.0, 1.0), v(0.0, 1.0)]), "green");
+    assert_eq!(material_of([v(0.0, 1.0), v(1.0, 1.0), v(0.0, 2.0)]), "red");
+    assert_eq!(material_of([v(1.0, 1.0), v(1.0, 2.0), v(0.0, 2.0)]), "blue");
+    assert_eq!(material_of([v(0.0, 0.0), v(1.0, 1.0), v(1.0, 0.0)]), "green");
+    assert_eq!(material_of([v(0.0, 0.0), v(1.0, 2.0), v(0.0, 2.0)]), "default");
+  }
+
+  #[test]
+  fn non_ascii_paths() {
+    let scene = load("modèle-模型/ünïcode.obj");
+    check_scene(&scene);
+    assert_eq!(scene.materials.len(), 1);
+    assert_eq!(scene.materials[0].name, "rouge");
+    assert_eq!(scene.materials[0].diffuse, [1.0, 0.0, 0.0]);
+  }
+
+  #[cfg(not(windows))]
+  #[test]
+  fn backslash_texture_paths() {
+    let obj = fixture("modèle-模型/ünïcode.obj");
+    let obj = Location::Local(obj);
+    assert_eq!(texture_path(&obj, "bois.png"), Location::Local(fixture("modèle-模型/bois.png")));
+    assert_eq!(
+      texture_path(&obj, "..\\modèle-模型\\bois.png"),
+      Location::Local(fixture("modèle-模型/../modèle-模型/bois.png"))
+    );
+  }
+
+  #[test]
+  fn empty_scene_is_an_error() {
+    assert!(load_with("empty.obj", &SceneOptions::default()).is_err());
+  }
+
+  #[test]
+  fn missing_file_is_an_error() {
+    assert!(load_with("does_not_exist.obj", &SceneOptions::default()).is_err());
+  }
+
+  #[test]
+  fn inverted_cube_winding() {
+    let kept = load("inverted_cube.obj");
+    assert_eq!(kept.warnings.len(), 1);
+    assert!(closed_volumes(&kept)[0] < 0.0);
+
+    let auto = load_with("inverted_cube.obj", &SceneOptions { winding: Winding::Auto }).unwrap();
+    check_scene(&auto);
+    assert!(auto.warnings.is_empty());
+    assert!(closed_volumes(&auto)[0] > 0.0);
+
+    let flipped = load_with("cube.obj", &SceneOptions { winding: Winding::Flip }).unwrap();
+    check_scene(&flipped);
+    assert_eq!(flipped.warnings.len(), 1);
+  }
+
+  // Writes a generated OBJ to a unique temporary file
+  fn write_temp_obj(contents: &str) -> PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let path = std::env::temp_dir().join(format!(
+      "oasis-loader-{}-{}.obj",
+      std::process::id(),
+      NEXT.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&path, contents).unwrap();
+    path
+  }
+
+  // Vertex index, and whether it is written as a negative index
+  type Corner = (prop::sample::Index, bool);
+  type GeneratedMesh = (Vec<[f32; 3]>, Vec<[Corner; 3]>);
+
+  // Vertices plus faces of three corners each
+  fn mesh() -> impl Strategy<Value = GeneratedMesh> {
+    let vertex = prop::array::uniform3(-1000.0f32..1000.0);
+    let corner = (any::<prop::sample::Index>(), any::<bool>());
+    (
+      prop::collection::vec(vertex, 3..40),
+      prop::collection::vec([corner.clone(), corner.clone(), corner], 1..60),
+    )
+  }
+
+  proptest! {
+    #[test]
+    fn generated_meshes_hold_invariants((vertices, faces) in mesh()) {
+      let mut obj = String::from("o generated\n");
+      for v in &vertices {
+        obj.push_str(&format!("v {} {} {}\n", v[0], v[1], v[2]));
+      }
+      let mut expected = Vec::new();
+      for face in &faces {
+        obj.push('f');
+        for (index, negative) in face {
+          let i = index.index(vertices.len());
+          expected.push(vertices[i]);
+          if *negative {
+            obj.push_str(&format!(" {}", i as i64 - vertices.len() as i64));
+          } else {
+            obj.push_str(&format!(" {}", i + 1));
+          }
+        }
+        obj.push('\n');
+      }
+
+      let path = write_temp_obj(&obj);
+      let scene = load_obj_scene(&Location::Local(path.clone()), &SceneOptions::default());
+      fs::remove_file(&path).unwrap();
+      let scene = scene.unwrap();
+
+      check_scene(&scene);
+      prop_assert_eq!(scene.triangles_indexed.len(), faces.len());
+      prop_assert_eq!(&scene.triangles, &expected);
+
+      // The AABB is tight around the loaded vertices
+      for k in 0..3 {
+        let min = scene.vertices.iter().map(|v| v[k]).fold(f32::MAX, f32::min);
+        let max = scene.vertices.iter().map(|v| v[k]).fold(f32::MIN, f32::max);
+        prop_assert_eq!(scene.aabb.min[k], min);
+        prop_assert_eq!(scene.aabb.max[k], max);
+      }
+    }
+  }
+}
@@ -0,0 +1,247 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use oasis_format::{read_nodes, validate_children, Node};
+
+use crate::cli::output_path;
+
+// Split export for consumers that only need occupancy. The topology file holds
+// the children arrays and names the optional sidecars next to it:
+//   magic "OTOP", u32 version, u64 node count, u32 sidecar count,
+//   per sidecar: 4-byte kind + u32 name length + UTF-8 file name,
+//   then `node count` x 8 i32 children.
+// The color sidecar is magic "OCOL", u32 version, u64 node count, then
+// `node count` x 4 f32 YUV values. Attribute sidecars use the .svattr format.
+pub const TOPOLOGY_MAGIC: &[u8; 4] = b"OTOP";
+pub const COLOR_MAGIC: &[u8; 4] = b"OCOL";
+pub const SPLIT_VERSION: u32 = 1;
+pub const TOPOLOGY_EXTENSION: &str = "svtopo";
+pub const COLOR_EXTENSION: &str = "svcol";
+
+pub const COLOR_SIDECAR: &[u8; 4] = b"COLR";
+pub const ATTRIBUTE_SIDECAR: &[u8; 4] = b"ATTR";
+
+pub struct Sidecar {
+  pub kind: [u8; 4],
+  // Relative to the topology file
+  pub file_name: String,
+}
+
+fn invalid_data(message: String) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+  let mut buf = [0u8; 4];
+  reader.read_exact(&mut buf)?;
+  Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+  let mut buf = [0u8; 8];
+  reader.read_exact(&mut buf)?;
+  Ok(u64::from_le_bytes(buf))
+}
+
+// Writes `<output_name>.svtopo` and `<output_name>.svcol`. `attributes` names an
+// already written attribute sidecar to reference from the header.
+pub fn write_split(nodes: &[Node], output_name: &Path, attributes: Option<&Path>) -> io::Result<()> {
+  // The header stores names as UTF-8, a lossy name would point at nothing
+  let file_name = |path: &Path| -> io::Result<String> {
+    path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .map(String::from)
+      .ok_or_else(|| {
+        io::Error::new(
+          io::ErrorKind::InvalidInput,
+          format!("sidecar name '{}' is not valid UTF-8", path.display()),
+        )
+      })
+  };
+
+  let color_path = output_path(output_name, COLOR_EXTENSION);
+  let mut sidecars = vec![Sidecar {
+    kind: *COLOR_SIDECAR,
+    file_name: file_name(&color_path)?,
+  }];
+  if let Some(path) = attributes {
+    sidecars.push(Sidecar {
+      kind: *ATTRIBUTE_SIDECAR,
+      file_name: file_name(path)?,
+    });
+  }
+
+  let mut topology = BufWriter::new(File::create(output_path(output_name, TOPOLOGY_EXTENSION))?);
+  topology.write_all(TOPOLOGY_MAGIC)?;
+  topology.write_all(&SPLIT_VERSION.to_le_bytes())?;
+  topology.write_all(&(nodes.len() as u64).to_le_bytes())?;
+  topology.write_all(&(sidecars.len() as u32).to_le_bytes())?;
+  for sidecar in &sidecars {
+    topology.write_all(&sidecar.kind)?;
+    topology.write_all(&(sidecar.file_name.len() as u32).to_le_bytes())?;
+    topology.write_all(sidecar.file_name.as_bytes())?;
+  }
+  for node in nodes {
+    for child in &node.children {
+      topology.write_all(&child.to_le_bytes())?;
+    }
+  }
+  topology.flush()?;
+
+  let mut color = BufWriter::new(File::create(&color_path)?);
+  color.write_all(COLOR_MAGIC)?;
+  color.write_all(&SPLIT_VERSION.to_le_bytes())?;
+  color.write_all(&(nodes.len() as u64).to_le_bytes())?;
+  for node in nodes {
+    for c in &node.yuv {
+      color.write_all(&c.to_le_bytes())?;
+    }
+  }
+  color.flush()
+}
+
+// Reads a topology file and, if its color sidecar is present next to it, the
+// node colors. Without the sidecar all colors are zero.
+pub fn read_split<P: AsRef<Path>>(path: P) -> io::Result<(Vec<Node>, Vec<Sidecar>)> {
+  let path = path.as_ref();
+  let mut reader = BufReader::new(File::open(path)?);
+
+  let mut magic = [0u8; 4];
+  reader.read_exact(&mut magic)?;
+  if &magic != TOPOLOGY_MAGIC || read_u32(&mut reader)? != SPLIT_VERSION {
+    return Err(invalid_data(format!("'{}' is not a supported topology file", path.display())));
+  }
+  let count = read_u64(&mut reader)? as usize;
+
+  let sidecar_count = read_u32(&mut reader)?;
+  let mut sidecars = Vec::new();
+  for _ in 0..sidecar_count {
+    let mut kind = [0u8; 4];
+    reader.read_exact(&mut kind)?;
+    let len = read_u32(&mut reader)? as usize;
+    let mut name = vec![0u8; len];
+    reader.read_exact(&mut name)?;
+    sidecars.push(Sidecar {
+      kind,
+      file_name: String::from_utf8_lossy(&name).into_owned(),
+    });
+  }
+
+  // Don't trust the header for the up-front allocation
+  let mut nodes = Vec::with_capacity(count.min(1 << 20));
+  let mut buf = [0u8; 32];
+  for _ in 0..count {
+    reader.read_exact(&mut buf)?;
+    let mut node = Node::default();
+    for (i, child) in node.children.iter_mut().enumerate() {
+      *child = i32::from_le_bytes(buf[4 * i..4 * i + 4].try_into().unwrap());
+    }
+    nodes.push(node);
+  }
+  validate_children(&nodes).map_err(|e| invalid_data(e.to_string()))?;
+
+  let dir = path.parent().unwrap_or_else(|| Path::new(""));
+  let color = sidecars.iter().find(|s| &s.kind == COLOR_SIDECAR);
+  if let Some(color_path) = color.map(|s| dir.join(&s.file_name)).filter(|p| p.exists()) {
+    let mut reader = BufReader::new(File::open(&color_path)?);
+    reader.read_exact(&mut magic)?;
+    if &magic != COLOR_MAGIC || read_u32(&mut reader)? != SPLIT_VERSION {
+      return Err(invalid_data(format!("'{}' is not a supported color file", color_path.display())));
+    }
+    if read_u64(&mut reader)? as usize != count {
+      return Err(invalid_data(format!(
+        "'{}' does not match the node count of '{}'",
+        color_path.display(),
+        path.display()
+      )));
+    }
+
+    let mut buf = [0u8; 16];
+    for node in nodes.iter_mut() {
+      reader.read_exact(&mut buf)?;
+      for (i, c) in node.yuv.iter_mut().enumerate() {
+        *c = f32::from_le_bytes(buf[4 * i..4 * i + 4].try_into().unwrap());
+      }
+    }
+  }
+
+  Ok((nodes, sidecars))
+}
+
+// Reads either a .svdag or a split topology file, picked by extension
+pub fn read_pool<P: AsRef<Path>>(path: P) -> io::Result<Vec<Node>> {
+  let path = path.as_ref();
+  if path.extension().is_some_and(|ext| ext == TOPOLOGY_EXTENSION) {
+    read_split(path).map(|(nodes, _)| nodes)
+  } else {
+    read_nodes(path)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use std::env;
+  use std::fs;
+
+  use crate::attributes::{write_attributes, NodeAttributes, ATTRIBUTE_EXTENSION, ATTRIBUTE_MAGIC};
+  use crate::test_pools::sample_pool;
+
+  #[test]
+  fn split_round_trip() {
+    let dir = env::temp_dir().join(format!("oasis-split-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let output_name = dir.join("pool");
+    let nodes = sample_pool();
+
+    let attribute_path = output_path(&output_name, ATTRIBUTE_EXTENSION);
+    let attributes = NodeAttributes {
+      names: vec![String::from("ao")],
+      values: vec![(0..nodes.len()).map(|i| i as f32 * 0.25).collect()],
+    };
+    write_attributes(&attributes, &attribute_path).unwrap();
+    write_split(&nodes, &output_name, Some(&attribute_path)).unwrap();
+
+    let topology_path = output_path(&output_name, TOPOLOGY_EXTENSION);
+    let (read, sidecars) = read_split(&topology_path).unwrap();
+    assert_eq!(read, nodes);
+    assert_eq!(read_pool(&topology_path).unwrap(), nodes);
+
+    let kinds: Vec<[u8; 4]> = sidecars.iter().map(|s| s.kind).collect();
+    assert_eq!(kinds, [*COLOR_SIDECAR, *ATTRIBUTE_SIDECAR]);
+    let attribute_sidecar = &sidecars[1];
+    assert_eq!(attribute_sidecar.file_name, "pool.svattr");
+    let bytes = fs::read(dir.join(&attribute_sidecar.file_name)).unwrap();
+    assert_eq!(&bytes[..4], ATTRIBUTE_MAGIC);
+    assert_eq!(u64::from_le_bytes(bytes[8..16].try_into().unwrap()), nodes.len() as u64);
+
+    // Occupancy alone still loads, with every color zero
+    fs::remove_file(output_path(&output_name, COLOR_EXTENSION)).unwrap();
+    let (read, _) = read_split(&topology_path).unwrap();
+    for (read, node) in read.iter().zip(&nodes) {
+      assert_eq!(read.children, node.children);
+      assert_eq!(read.yuv, [0.0; 4]);
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+  }
+}
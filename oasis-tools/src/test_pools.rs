@@ -0,0 +1,88 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+// Small hand-built pools shared by the pool editing tests
+
+use oasis_format::Node;
+
+use crate::inspect::sample_point;
+
+pub fn node(children: [i32; 8], yuv: [f32; 3]) -> Node {
+  Node {
+    children,
+    yuv: [yuv[0], yuv[1], yuv[2], 0.0],
+  }
+}
+
+// Three levels deep with a solid leaf at level 1, empty slots, distinct
+// materials and node 3 shared by two parents
+pub fn sample_pool() -> Vec<Node> {
+  vec![
+    node([2, 3, -1, 0, 2, 0, 0, -2], [100.0, 128.0, 128.0]),
+    node([-1, 0, 0, 4, 0, -3, 0, 0], [20.0, 0.0, 0.0]),
+    node([0, -1, 0, 0, 0, 0, 0, 4], [-30.0, 10.0, 0.0]),
+    node([-2, 0, 0, 0, 0, 0, -1, 0], [5.0, 5.0, 5.0]),
+  ]
+}
+
+// Color of every cell of a 2^level grid, None where it is empty. Indexed
+// x fastest, then y, then z.
+pub fn voxels(nodes: &[Node], level: u32) -> Vec<Option<[f32; 3]>> {
+  let n = 1u32 << level;
+  let mut cells = Vec::with_capacity((n * n * n) as usize);
+  for z in 0..n {
+    for y in 0..n {
+      for x in 0..n {
+        let center = [x, y, z].map(|c| (c as f32 + 0.5) / n as f32);
+        cells.push(sample_point(nodes, center));
+      }
+    }
+  }
+  cells
+}
+
+// Pool `depth` levels deep with a leaf in every cell (integer coordinates at
+// that depth) that `occupied` accepts. The root ends up at index 0.
+pub fn pool_from_cells(depth: u32, occupied: &dyn Fn([u32; 3]) -> bool) -> Vec<Node> {
+  let mut nodes = Vec::new();
+  build_cells(&mut nodes, depth, 0, [0; 3], occupied);
+  nodes
+}
+
+fn build_cells(nodes: &mut Vec<Node>, depth: u32, level: u32, origin: [u32; 3], occupied: &dyn Fn([u32; 3]) -> bool) -> Option<usize> {
+  let index = nodes.len();
+  nodes.push(Node::default());
+
+  let half = 1u32 << (depth - level - 1);
+  let mut children = [0i32; 8];
+  for (slot, child) in children.iter_mut().enumerate() {
+    let child_origin = [0, 1, 2].map(|k| origin[k] + if slot & (1 << k) != 0 { half } else { 0 });
+    if level + 1 == depth {
+      if occupied(child_origin) {
+        *child = -1;
+      }
+    } else if let Some(node) = build_cells(nodes, depth, level + 1, child_origin, occupied) {
+      *child = node as i32 + 1;
+    }
+  }
+
+  if children == [0; 8] {
+    nodes.truncate(index);
+    return None;
+  }
+  nodes[index].children = children;
+  Some(index)
+}
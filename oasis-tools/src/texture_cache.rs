@@ -0,0 +1,301 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::hash::Fnv1a128;
+
+const MAGIC: &[u8; 4] = b"OTEX";
+const VERSION: u32 = 2;
+// Magic, version, source length, source hash, width, height, channels
+const HEADER_SIZE: usize = 4 + 4 + 8 + 16 + 4 + 4 + 4;
+const EXTENSION: &str = "tex";
+
+// Bump whenever the preprocessing in `load_textures` changes, so stale
+// entries are never returned.
+const PREPROCESS_TAG: &[u8] = b"flipv-rgb8";
+
+pub const DEFAULT_LIMIT_MB: u64 = 1024;
+
+// Texels of a decoded and preprocessed texture
+pub struct CachedTexture {
+  pub width: u32,
+  pub height: u32,
+  pub channels: u32,
+  pub data: Vec<u8>,
+}
+
+// Identifies a source file by its contents. Both fields are stored in the
+// entry and compared on read, so a hash collision can't return the wrong
+// texture unless the lengths match too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureKey {
+  pub len: u64,
+  pub hash: u128,
+}
+
+// On-disk cache of preprocessed textures keyed by the source file contents
+pub struct TextureCache {
+  dir: PathBuf,
+  limit_bytes: u64,
+}
+
+fn default_cache_dir() -> Option<PathBuf> {
+  if let Some(dir) = env::var_os("OASIS_CACHE_DIR") {
+    return Some(PathBuf::from(dir));
+  }
+  if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+    return Some(PathBuf::from(dir).join("oasis-builder"));
+  }
+  if cfg!(windows) {
+    if let Some(dir) = env::var_os("LOCALAPPDATA") {
+      return Some(PathBuf::from(dir).join("oasis-builder").join("cache"));
+    }
+  }
+  env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache").join("oasis-builder"))
+}
+
+impl TextureCache {
+  // Opens (creating if needed) the default cache directory. Returns None
+  // when no cache location is available, in which case caching is skipped.
+  pub fn open(limit_mb: u64) -> Option<Self> {
+    let dir = default_cache_dir()?;
+    if let Err(e) = fs::create_dir_all(&dir) {
+      eprintln!("Warning: texture cache disabled, can't create '{}': {}", dir.display(), e);
+      return None;
+    }
+    Some(Self::in_dir(dir, limit_mb))
+  }
+
+  // Cache in an existing directory
+  pub fn in_dir(dir: PathBuf, limit_mb: u64) -> Self {
+    TextureCache {
+      dir,
+      limit_bytes: limit_mb.saturating_mul(1024 * 1024),
+    }
+  }
+
+  pub fn key(source: &[u8]) -> TextureKey {
+    let mut hasher = Fnv1a128::new();
+    hasher.update(PREPROCESS_TAG);
+    hasher.update(source);
+    TextureKey {
+      len: source.len() as u64,
+      hash: hasher.finish(),
+    }
+  }
+
+  fn entry_path(&self, key: TextureKey) -> PathBuf {
+    self.dir.join(format!("{:032x}.{}", key.hash, EXTENSION))
+  }
+
+  pub fn get(&self, key: TextureKey) -> Option<CachedTexture> {
+    let path = self.entry_path(key);
+    let mut file = File::open(&path).ok()?;
+
+    let mut header = [0u8; HEADER_SIZE];
+    file.read_exact(&mut header).ok()?;
+    let u32_at = |at: usize| u32::from_le_bytes(header[at..at + 4].try_into().unwrap());
+    if &header[0..4] != MAGIC || u32_at(4) != VERSION {
+      return None;
+    }
+    let stored = TextureKey {
+      len: u64::from_le_bytes(header[8..16].try_into().unwrap()),
+      hash: u128::from_le_bytes(header[16..32].try_into().unwrap()),
+    };
+    if stored != key {
+      return None;
+    }
+    let width = u32_at(32);
+    let height = u32_at(36);
+    let channels = u32_at(40);
+
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).ok()?;
+    if data.len() as u64 != width as u64 * height as u64 * channels as u64 {
+      return None;
+    }
+
+    // Mark as recently used for eviction. Setting the time needs a writable
+    // handle on Windows; a read-only cache just evicts oldest first.
+    if let Err(e) = touch(&path) {
+      eprintln!("Warning: can't update texture cache entry '{}': {}", path.display(), e);
+    }
+
+    Some(CachedTexture {
+      width,
+      height,
+      channels,
+      data,
+    })
+  }
+
+  pub fn put(&self, key: TextureKey, texture: &CachedTexture) -> io::Result<()> {
+    let path = self.entry_path(key);
+    let tmp_path = path.with_extension(format!("{}.tmp", EXTENSION));
+
+    {
+      let mut file = File::create(&tmp_path)?;
+      file.write_all(MAGIC)?;
+      file.write_all(&VERSION.to_le_bytes())?;
+      file.write_all(&key.len.to_le_bytes())?;
+      file.write_all(&key.hash.to_le_bytes())?;
+      file.write_all(&texture.width.to_le_bytes())?;
+      file.write_all(&texture.height.to_le_bytes())?;
+      file.write_all(&texture.channels.to_le_bytes())?;
+      file.write_all(&texture.data)?;
+    }
+    // Rename so a concurrent build never reads a half written entry
+    fs::rename(&tmp_path, &path)?;
+
+    self.evict()
+  }
+
+  // Deletes least recently used entries until the cache fits its limit
+  fn evict(&self) -> io::Result<()> {
+    let mut entries: Vec<(SystemTime, u64, PathBuf)> = Vec::new();
+    let mut total: u64 = 0;
+
+    for entry in fs::read_dir(&self.dir)? {
+      let entry = entry?;
+      let path = entry.path();
+      if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+        continue;
+      }
+      let meta = match entry.metadata() {
+        Ok(meta) => meta,
+        // Evicted by a concurrent build in the meantime
+        Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+        Err(e) => return Err(e),
+      };
+      let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+      total += meta.len();
+      entries.push((modified, meta.len(), path));
+    }
+
+    if total <= self.limit_bytes {
+      return Ok(());
+    }
+
+    entries.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in entries {
+      if total <= self.limit_bytes {
+        break;
+      }
+      match fs::remove_file(&path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+      }
+      total -= len;
+    }
+    Ok(())
+  }
+}
+
+fn touch(path: &Path) -> io::Result<()> {
+  File::options().write(true).open(path)?.set_modified(SystemTime::now())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::time::Duration;
+
+  // Empty cache in a unique temporary directory
+  fn temp_cache() -> TextureCache {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let dir = env::temp_dir().join(format!(
+      "oasis-texture-cache-{}-{}",
+      std::process::id(),
+      NEXT.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    TextureCache::in_dir(dir, DEFAULT_LIMIT_MB)
+  }
+
+  fn texture() -> CachedTexture {
+    CachedTexture {
+      width: 2,
+      height: 1,
+      channels: 3,
+      data: vec![1, 2, 3, 4, 5, 6],
+    }
+  }
+
+  #[test]
+  fn entries_round_trip() {
+    let cache = temp_cache();
+    let key = TextureCache::key(b"source image");
+    assert!(cache.get(key).is_none());
+    cache.put(key, &texture()).unwrap();
+
+    let cached = cache.get(key).unwrap();
+    assert_eq!((cached.width, cached.height, cached.channels), (2, 1, 3));
+    assert_eq!(cached.data, texture().data);
+    fs::remove_dir_all(&cache.dir).unwrap();
+  }
+
+  #[test]
+  fn colliding_hashes_miss() {
+    let cache = temp_cache();
+    let key = TextureCache::key(b"source image");
+    cache.put(key, &texture()).unwrap();
+
+    // Same file name, different source length
+    let collision = TextureKey { len: key.len + 1, ..key };
+    assert!(cache.get(collision).is_none());
+    fs::remove_dir_all(&cache.dir).unwrap();
+  }
+
+  #[test]
+  fn hits_refresh_the_entry_time() {
+    let cache = temp_cache();
+    let key = TextureCache::key(b"source image");
+    cache.put(key, &texture()).unwrap();
+
+    let path = cache.entry_path(key);
+    let old = SystemTime::now() - Duration::from_secs(3600);
+    File::options().write(true).open(&path).unwrap().set_modified(old).unwrap();
+    cache.get(key).unwrap();
+    assert!(fs::metadata(&path).unwrap().modified().unwrap() > old);
+    fs::remove_dir_all(&cache.dir).unwrap();
+  }
+
+  #[test]
+  fn eviction_drops_the_least_recently_used() {
+    let mut cache = temp_cache();
+    let (first, second) = (TextureCache::key(b"first"), TextureCache::key(b"second"));
+    cache.put(first, &texture()).unwrap();
+    cache.put(second, &texture()).unwrap();
+
+    let old = SystemTime::now() - Duration::from_secs(3600);
+    File::options().write(true).open(cache.entry_path(second)).unwrap().set_modified(old).unwrap();
+
+    // Room for a single entry
+    cache.limit_bytes = (HEADER_SIZE + texture().data.len()) as u64;
+    cache.evict().unwrap();
+    assert!(cache.get(first).is_some());
+    assert!(cache.get(second).is_none());
+    fs::remove_dir_all(&cache.dir).unwrap();
+  }
+}
@@ -0,0 +1,266 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+// Marks an entry inside a zip archive: `assets.zip!models/scene.obj`
+const ARCHIVE_SUFFIX: &str = ".zip!";
+
+// Largest archive entry or download read into memory. Zip headers and
+// servers can claim any size, so nothing is preallocated from them and
+// reading stops here.
+const MAX_READ_BYTES: u64 = 2 << 30;
+
+// A server that accepts the connection but stops sending fails the read
+// instead of hanging the build. There's no limit on the whole transfer, a
+// large model on a slow link is fine as long as data keeps arriving.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn http_agent() -> &'static ureq::Agent {
+  static AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+  AGENT.get_or_init(|| {
+    ureq::AgentBuilder::new()
+      .timeout_connect(CONNECT_TIMEOUT)
+      .timeout_read(READ_TIMEOUT)
+      .build()
+  })
+}
+
+// Reads all of `reader`, failing once it yields more than `limit` bytes
+fn read_limited(reader: impl Read, limit: u64, location: &Location) -> io::Result<Vec<u8>> {
+  let mut data = Vec::new();
+  reader.take(limit + 1).read_to_end(&mut data)?;
+  if data.len() as u64 > limit {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      format!("'{}' is larger than {} MB", location, limit / (1024 * 1024)),
+    ));
+  }
+  Ok(data)
+}
+
+// Where a model or a file it refers to is read from. Files named by a model
+// (MTL libraries, textures) resolve relative to it within the same backend,
+// so an OBJ in an archive finds its textures in that archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+  Local(PathBuf),
+  // `entry` is '/' separated with no leading slash, as zip stores it
+  Zip { archive: PathBuf, entry: String },
+  Http(String),
+}
+
+impl Location {
+  // Reads a command line argument. Existing files win over the archive
+  // syntax, and arguments that aren't valid UTF-8 are always local paths.
+  pub fn parse(arg: &OsStr) -> Self {
+    let Some(text) = arg.to_str() else {
+      return Location::Local(PathBuf::from(arg));
+    };
+    if text.starts_with("http://") || text.starts_with("https://") {
+      return Location::Http(text.to_string());
+    }
+    if !PathBuf::from(text).exists() {
+      // ASCII lowercasing keeps byte offsets, so the split lines up with `text`
+      if let Some(index) = text.to_ascii_lowercase().find(ARCHIVE_SUFFIX) {
+        let (archive, entry) = text.split_at(index + ARCHIVE_SUFFIX.len());
+        return Location::Zip {
+          archive: PathBuf::from(&archive[..archive.len() - 1]),
+          entry: join_relative("", entry),
+        };
+      }
+    }
+    Location::Local(PathBuf::from(arg))
+  }
+
+  pub fn read(&self) -> io::Result<Vec<u8>> {
+    match self {
+      Location::Local(path) => fs::read(path),
+      Location::Zip { archive, entry } => {
+        let mut zip = zip::ZipArchive::new(File::open(archive)?)?;
+        let mut file = zip.by_name(entry).map_err(|e| match e {
+          zip::result::ZipError::FileNotFound => io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no entry '{}' in '{}'", entry, archive.display()),
+          ),
+          e => e.into(),
+        })?;
+        read_limited(&mut file, MAX_READ_BYTES, self)
+      }
+      Location::Http(url) => {
+        let response = http_agent()
+          .get(url)
+          .call()
+          .map_err(|e| io::Error::other(format!("GET {} failed: {}", url, e)))?;
+        read_limited(response.into_reader(), MAX_READ_BYTES, self)
+      }
+    }
+  }
+
+  // Cheap existence check for argument validation. URLs are only checked
+  // when they are read, a request per check isn't worth it.
+  pub fn exists(&self) -> bool {
+    match self {
+      Location::Local(path) => path.is_file(),
+      Location::Zip { archive, entry } => File::open(archive)
+        .ok()
+        .and_then(|file| zip::ZipArchive::new(file).ok())
+        .is_some_and(|mut zip| zip.by_name(entry).is_ok()),
+      Location::Http(_) => true,
+    }
+  }
+
+  // Resolves `relative` against the directory this location is in. Archive
+  // entries and URLs always use '/', so backslashes from Windows MTL files
+  // are converted for them; local paths are joined as written.
+  pub fn sibling(&self, relative: &str) -> Location {
+    match self {
+      Location::Local(path) => Location::Local(path.parent().unwrap_or(path).join(relative)),
+      Location::Zip { archive, entry } => Location::Zip {
+        archive: archive.clone(),
+        entry: join_relative(entry, relative),
+      },
+      Location::Http(_) if relative.contains("://") => Location::Http(relative.to_string()),
+      Location::Http(url) => {
+        // Split off "scheme://host", the rest is the path to resolve against
+        let host_start = url.find("://").map_or(0, |i| i + 3);
+        let path_start = url[host_start..].find('/').map_or(url.len(), |i| host_start + i);
+        let path = url[path_start..].split(['?', '#']).next().unwrap_or("");
+        Location::Http(format!("{}/{}", &url[..path_start], join_relative(path, relative)))
+      }
+    }
+  }
+}
+
+impl fmt::Display for Location {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Location::Local(path) => write!(f, "{}", path.display()),
+      Location::Zip { archive, entry } => write!(f, "{}!{}", archive.display(), entry),
+      Location::Http(url) => write!(f, "{}", url),
+    }
+  }
+}
+
+// Joins `relative` onto the directory of the '/' separated `base`, folding
+// "." and ".." segments
+fn join_relative(base: &str, relative: &str) -> String {
+  let relative = relative.replace('\\', "/");
+  let mut parts: Vec<&str> = Vec::new();
+  if !relative.starts_with('/') {
+    parts.extend(base.split('/').filter(|part| !part.is_empty()));
+    // Drop the file name, keeping its directory
+    if !base.ends_with('/') {
+      parts.pop();
+    }
+  }
+  for part in relative.split('/') {
+    match part {
+      "" | "." => {}
+      ".." => {
+        parts.pop();
+      }
+      part => parts.push(part),
+    }
+  }
+  parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_stop_at_the_limit() {
+    let location = Location::Http(String::from("https://example.com/big.obj"));
+    assert_eq!(read_limited(&[7u8; 16][..], 16, &location).unwrap(), [7u8; 16]);
+    let error = read_limited(&[7u8; 17][..], 16, &location).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+  }
+
+  #[test]
+  fn arguments_pick_a_backend() {
+    assert_eq!(
+      Location::parse(OsStr::new("models/scene.obj")),
+      Location::Local(PathBuf::from("models/scene.obj"))
+    );
+    assert_eq!(
+      Location::parse(OsStr::new("assets/Pack.ZIP!/models\\scene.obj")),
+      Location::Zip {
+        archive: PathBuf::from("assets/Pack.ZIP"),
+        entry: String::from("models/scene.obj"),
+      }
+    );
+    assert_eq!(
+      Location::parse(OsStr::new("https://example.com/assets/scene.obj")),
+      Location::Http(String::from("https://example.com/assets/scene.obj"))
+    );
+  }
+
+  #[test]
+  fn archive_siblings_stay_in_the_archive() {
+    let obj = Location::parse(OsStr::new("pack.zip!models/scene.obj"));
+    assert_eq!(
+      obj.sibling("..\\textures\\wood.png"),
+      Location::Zip {
+        archive: PathBuf::from("pack.zip"),
+        entry: String::from("textures/wood.png"),
+      }
+    );
+    assert_eq!(obj.sibling("./scene.mtl").to_string(), "pack.zip!models/scene.mtl");
+  }
+
+  #[test]
+  fn url_siblings_resolve_against_the_path() {
+    let obj = Location::Http(String::from("https://example.com/a/b/scene.obj?token=1"));
+    assert_eq!(obj.sibling("scene.mtl").to_string(), "https://example.com/a/b/scene.mtl");
+    assert_eq!(obj.sibling("../tex/wood.png").to_string(), "https://example.com/a/tex/wood.png");
+    assert_eq!(obj.sibling("/wood.png").to_string(), "https://example.com/wood.png");
+    assert_eq!(
+      obj.sibling("https://cdn.example.com/wood.png").to_string(),
+      "https://cdn.example.com/wood.png"
+    );
+  }
+
+  #[test]
+  fn zip_entries_are_read() {
+    use std::io::Write;
+
+    let archive = std::env::temp_dir().join(format!("oasis-vfs-{}.zip", std::process::id()));
+    let mut writer = zip::ZipWriter::new(File::create(&archive).unwrap());
+    writer.start_file("models/scene.obj", zip::write::FileOptions::default()).unwrap();
+    writer.write_all(b"v 0 0 0\n").unwrap();
+    writer.finish().unwrap();
+
+    let obj = Location::Zip {
+      archive: archive.clone(),
+      entry: String::from("models/scene.obj"),
+    };
+    assert!(obj.exists());
+    assert_eq!(obj.read().unwrap(), b"v 0 0 0\n");
+    let missing = obj.sibling("scene.mtl");
+    assert!(!missing.exists());
+    assert_eq!(missing.read().unwrap_err().kind(), io::ErrorKind::NotFound);
+    fs::remove_file(&archive).unwrap();
+  }
+}
@@ -0,0 +1,44 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fmt;
+
+/// Errors from the safe wrapper
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+  /// A name or path can't be handed to the C API
+  InvalidString(String),
+  /// A `BuildConfig` outside the limits the library and viewer support
+  InvalidConfig(String),
+  /// Texture data that doesn't match its dimensions
+  InvalidTexture(String),
+  /// A library call returned a null handle, holds the name of the C function
+  Library(&'static str),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::InvalidString(message) | Error::InvalidConfig(message) | Error::InvalidTexture(message) => {
+        write!(f, "{}", message)
+      }
+      Error::Library(call) => write!(f, "{} failed", call),
+    }
+  }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,76 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+//! Conversions for strings handed to the C API, which only takes narrow
+//! `const char*` paths and names.
+
+use std::ffi::CString;
+use std::path::Path;
+
+/// Converts a path for the C API without going through `String`.
+///
+/// On Unix the OS bytes are passed through untouched, so names that aren't
+/// valid UTF-8 still open. The API has no wide-char entry points, so on
+/// Windows the path is passed as UTF-8 and must be valid Unicode.
+///
+/// # Errors
+///
+/// Fails if the path contains an interior NUL byte, which can't be
+/// represented in a C string, or on Windows if it isn't valid Unicode. The
+/// message names the offending path.
+pub fn path_to_cstring(path: &Path) -> Result<CString, String> {
+  #[cfg(unix)]
+  let bytes = {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_os_str().as_bytes().to_vec()
+  };
+  #[cfg(not(unix))]
+  let bytes = path
+    .to_str()
+    .ok_or_else(|| format!("path '{}' is not valid Unicode", path.display()))?
+    .as_bytes()
+    .to_vec();
+
+  CString::new(bytes).map_err(|_| format!("path '{}' contains a NUL byte", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unicode_paths_round_trip() {
+    let path = Path::new("modèle-模型/ünïcode.svdag");
+    let c_path = path_to_cstring(path).unwrap();
+    assert_eq!(c_path.to_str().unwrap(), "modèle-模型/ünïcode.svdag");
+  }
+
+  #[test]
+  fn nul_bytes_are_rejected() {
+    assert!(path_to_cstring(Path::new("pool\0.svdag")).is_err());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn non_utf8_bytes_pass_through() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let raw = b"pool-\xff\xfe.svdag";
+    let c_path = path_to_cstring(Path::new(OsStr::from_bytes(raw))).unwrap();
+    assert_eq!(c_path.as_bytes(), raw);
+  }
+}
@@ -0,0 +1,41 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+//! Safe wrapper over the Oasis C API.
+//!
+//! Describe geometry, materials and textures in a [`Scene`], voxelize it into
+//! a sparse voxel DAG with [`NodePool::build`], and write the nodes out with
+//! [`format::write_nodes`]. Pools saved by the library load back with
+//! [`NodePool::deserialize`].
+//!
+//! Everything exported here follows semver. Config structs are
+//! `#[non_exhaustive]`, so new options can be added in minor releases; build
+//! them with their constructors rather than struct literals.
+
+#![warn(missing_docs)]
+
+pub mod ffi;
+
+mod error;
+mod pool;
+mod scene;
+
+pub use error::Error;
+pub use pool::{BuildConfig, NodePool, MAX_DEPTH};
+pub use scene::{Material, Scene, TriangleIndexed};
+
+pub use oasis_format as format;
+pub use oasis_format::Node;
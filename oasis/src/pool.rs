@@ -0,0 +1,188 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::marker::PhantomData;
+use std::mem;
+use std::path::Path;
+use std::slice;
+
+use oasis_format::Node;
+use oasis_sys::*;
+
+use crate::error::Error;
+use crate::ffi::path_to_cstring;
+use crate::scene::Scene;
+
+/// Deepest octree the viewer can traverse (`MAX_DAG_DEPTH` in its shader).
+/// oasis.h exposes no limits to query, so this is the tightest bound known.
+pub const MAX_DEPTH: u8 = 23;
+
+const _: () = assert!(mem::size_of::<Node>() == mem::size_of::<node_t>());
+
+/// Voxelization settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BuildConfig {
+  /// Octree depth, the scene bounds are split into 2^depth voxels per axis
+  pub depth: u8,
+  /// Step levels, passed through to the library which currently ignores them
+  pub step_level: u8,
+}
+
+impl BuildConfig {
+  /// Settings for an octree `depth` levels deep, with no step levels
+  pub fn new(depth: u8) -> Self {
+    Self { depth, step_level: 0 }
+  }
+
+  /// Sets the step levels handed to the library
+  pub fn with_step_level(mut self, step_level: u8) -> Self {
+    self.step_level = step_level;
+    self
+  }
+
+  fn validate(&self) -> Result<(), Error> {
+    if !(1..=MAX_DEPTH).contains(&self.depth) {
+      return Err(Error::InvalidConfig(format!(
+        "depth must be between 1 and {}, got {}",
+        MAX_DEPTH, self.depth
+      )));
+    }
+    if self.step_level > MAX_DEPTH {
+      return Err(Error::InvalidConfig(format!(
+        "step_level must be between 0 and {}, got {}",
+        MAX_DEPTH, self.step_level
+      )));
+    }
+    Ok(())
+  }
+}
+
+enum Owner {
+  Builder(oasis_node_pool_builder_t),
+  Deserialized(oasis_node_pool_t),
+}
+
+/// Node pool owned by the C library, freed on drop.
+///
+/// A built pool borrows its [`Scene`]. The pool belongs to the builder, and
+/// the library doesn't promise the builder has copied everything it needs
+/// out of the scene, so the scene has to outlive both. Deserialized pools
+/// borrow nothing and are `NodePool<'static>`.
+///
+/// ```compile_fail,E0597
+/// # fn main() -> Result<(), oasis::Error> {
+/// use oasis::{BuildConfig, NodePool, Scene};
+///
+/// let pool = {
+///   let scene = Scene::new()?;
+///   NodePool::build(&scene, &BuildConfig::new(8))?
+/// };
+/// # drop(pool);
+/// # Ok(())
+/// # }
+/// ```
+pub struct NodePool<'s> {
+  owner: Owner,
+  pool: *mut node_pool_t,
+  _scene: PhantomData<&'s Scene<'s>>,
+}
+
+impl<'s> NodePool<'s> {
+  /// Voxelizes `scene` inside its bounds
+  pub fn build(scene: &'s Scene, config: &BuildConfig) -> Result<Self, Error> {
+    config.validate()?;
+    unsafe {
+      let builder = oasis_node_pool_builder_create();
+      if builder.is_null() {
+        return Err(Error::Library("oasis_node_pool_builder_create"));
+      }
+
+      oasis_node_pool_builder_build(builder, scene.raw(), config.depth, config.step_level);
+
+      let handle = oasis_node_pool_builder_get_pool(builder);
+      let pool = if handle.is_null() {
+        std::ptr::null_mut()
+      } else {
+        oasis_node_pool_get(handle)
+      };
+      if pool.is_null() {
+        oasis_node_pool_builder_destroy(builder);
+        return Err(Error::Library("oasis_node_pool_builder_get_pool"));
+      }
+      Ok(Self {
+        owner: Owner::Builder(builder),
+        pool,
+        _scene: PhantomData,
+      })
+    }
+  }
+
+  /// Loads a pool file through the library
+  pub fn deserialize(path: &Path) -> Result<NodePool<'static>, Error> {
+    let c_path = path_to_cstring(path).map_err(Error::InvalidString)?;
+    unsafe {
+      let handle = oasis_node_pool_deserialize(c_path.as_ptr());
+      if handle.is_null() {
+        return Err(Error::Library("oasis_node_pool_deserialize"));
+      }
+      let pool = oasis_node_pool_get(handle);
+      if pool.is_null() {
+        oasis_node_pool_destroy(handle);
+        return Err(Error::Library("oasis_node_pool_get"));
+      }
+      Ok(NodePool {
+        owner: Owner::Deserialized(handle),
+        pool,
+        _scene: PhantomData,
+      })
+    }
+  }
+
+  /// The nodes, root first, as owned by the library
+  pub fn as_slice(&self) -> &[Node] {
+    let pool = unsafe { &*self.pool };
+    if pool.nodes.is_null() {
+      return &[];
+    }
+    unsafe { slice::from_raw_parts(pool.nodes as *const Node, pool.count) }
+  }
+
+  /// Number of nodes in the pool
+  pub fn len(&self) -> usize {
+    self.as_slice().len()
+  }
+
+  /// True if the pool has no nodes
+  pub fn is_empty(&self) -> bool {
+    self.as_slice().is_empty()
+  }
+}
+
+impl Drop for NodePool<'_> {
+  fn drop(&mut self) {
+    unsafe {
+      match self.owner {
+        Owner::Builder(builder) => {
+          // Builder first, then the pool, as the original builder example did
+          oasis_node_pool_builder_destroy(builder);
+          oasis_node_pool_free(self.pool);
+        }
+        Owner::Deserialized(handle) => oasis_node_pool_destroy(handle),
+      }
+    }
+  }
+}
@@ -0,0 +1,197 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::mem;
+
+use oasis_sys::*;
+
+use crate::error::Error;
+
+/// Triangle indexing separate position and texture coordinate arrays, laid
+/// out as the C API's `tri_indexed_c_t`
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TriangleIndexed {
+  /// Corners as indices into the array given to `Scene::set_vertices`
+  pub v_idx: [usize; 3],
+  /// Corners as indices into the array given to `Scene::set_tex_coords`
+  pub tc_idx: [usize; 3],
+  /// Index of the material in the order they were added to the scene
+  pub mat_idx: usize,
+}
+
+const _: () = assert!(mem::size_of::<TriangleIndexed>() == mem::size_of::<tri_indexed_c_t>());
+
+/// Material added to a scene, in the order triangles index them
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Material<'a> {
+  /// Material name, copied by the library
+  pub name: &'a str,
+  /// Name of a texture added with `Scene::add_texture`
+  pub texture: Option<&'a str>,
+  /// Diffuse color (MTL `Kd`)
+  pub diffuse: [f32; 3],
+  /// Specular color (MTL `Ks`)
+  pub specular: [f32; 3],
+  /// Ambient color (MTL `Ka`)
+  pub ambient: [f32; 3],
+  /// Specular exponent (MTL `Ns`)
+  pub exponent: f32,
+  /// Passed through to the library, 1.0 is opaque
+  pub transparency: f32,
+}
+
+impl<'a> Material<'a> {
+  /// Untextured white material
+  pub fn new(name: &'a str) -> Self {
+    Self {
+      name,
+      texture: None,
+      diffuse: [1.0; 3],
+      specular: [0.0; 3],
+      ambient: [0.0; 3],
+      exponent: 0.0,
+      transparency: 1.0,
+    }
+  }
+}
+
+fn c_string(kind: &str, value: &str) -> Result<CString, Error> {
+  CString::new(value).map_err(|_| Error::InvalidString(format!("{} name '{}' contains a NUL byte", kind, value)))
+}
+
+/// Scene handed to the C library for voxelization. Geometry arrays are
+/// borrowed for the scene's lifetime since the library may keep pointers to
+/// them; names and texture data are copied.
+pub struct Scene<'a> {
+  raw: oasis_scene_t,
+  _geometry: PhantomData<&'a [u8]>,
+}
+
+impl<'a> Scene<'a> {
+  /// Creates an empty scene
+  ///
+  /// # Errors
+  ///
+  /// [`Error::Library`] if the library fails to allocate the scene.
+  pub fn new() -> Result<Self, Error> {
+    let raw = unsafe { oasis_scene_create() };
+    if raw.is_null() {
+      return Err(Error::Library("oasis_scene_create"));
+    }
+    Ok(Self {
+      raw,
+      _geometry: PhantomData,
+    })
+  }
+
+  /// Vertex positions that `TriangleIndexed::v_idx` points into
+  pub fn set_vertices(&mut self, vertices: &'a [[f32; 3]]) {
+    unsafe { oasis_scene_set_vertices(self.raw, vertices.as_ptr() as *const vec3f_t, vertices.len()) }
+  }
+
+  /// Texture coordinates that `TriangleIndexed::tc_idx` points into
+  pub fn set_tex_coords(&mut self, tex_coords: &'a [[f32; 2]]) {
+    unsafe { oasis_scene_set_tex_coords(self.raw, tex_coords.as_ptr() as *const vec2f_t, tex_coords.len()) }
+  }
+
+  /// Triangle corners, three per triangle
+  pub fn set_raw_triangles(&mut self, corners: &'a [[f32; 3]]) {
+    unsafe { oasis_scene_set_raw_triangles(self.raw, corners.as_ptr() as *const vec3f_t, corners.len()) }
+  }
+
+  /// Triangles indexing the vertices, texture coordinates and materials
+  pub fn set_indexed_triangles(&mut self, triangles: &'a [TriangleIndexed]) {
+    unsafe {
+      oasis_scene_set_indexed_triangles(self.raw, triangles.as_ptr() as *const tri_indexed_c_t, triangles.len())
+    }
+  }
+
+  /// Region to voxelize
+  pub fn set_bounds(&mut self, min: [f32; 3], max: [f32; 3]) {
+    let bbox = bbox_c_t { min, max };
+    unsafe { oasis_scene_set_aabb(self.raw, &bbox) }
+  }
+
+  /// Adds a material, triangles refer to it by the order it was added in
+  ///
+  /// # Errors
+  ///
+  /// [`Error::InvalidString`] if the material or texture name contains a NUL
+  /// byte.
+  pub fn add_material(&mut self, material: &Material) -> Result<(), Error> {
+    // Both must outlive the call, a Rust `str` isn't NUL terminated
+    let name = c_string("material", material.name)?;
+    let texture = material.texture.map(|t| c_string("texture", t)).transpose()?;
+    let raw = material_c_t {
+      name: name.as_ptr(),
+      texture: texture.as_ref().map_or(std::ptr::null(), |t| t.as_ptr()),
+      diffuse: material.diffuse,
+      specular: material.specular,
+      ambient: material.ambient,
+      exponent: material.exponent,
+      transparancy: material.transparency,
+    };
+    unsafe { oasis_scene_add_material(self.raw, &raw) }
+    Ok(())
+  }
+
+  /// Adds tightly packed 8-bit texture data under `name`, rows bottom up
+  ///
+  /// # Errors
+  ///
+  /// [`Error::InvalidTexture`] if `data` doesn't hold `width * height *
+  /// channels` bytes or `channels` isn't 1 to 4, [`Error::InvalidString`] if
+  /// `name` contains a NUL byte.
+  pub fn add_texture(&mut self, name: &str, width: u32, height: u32, channels: u32, data: &[u8]) -> Result<(), Error> {
+    let expected = width as usize * height as usize * channels as usize;
+    if data.len() != expected || !(1..=4).contains(&channels) {
+      return Err(Error::InvalidTexture(format!(
+        "texture '{}' has {} bytes, expected {}x{} with {} channels",
+        name,
+        data.len(),
+        width,
+        height,
+        channels
+      )));
+    }
+    let c_name = c_string("texture", name)?;
+    unsafe {
+      oasis_scene_add_texture(
+        self.raw,
+        c_name.as_ptr(),
+        data.as_ptr(),
+        width as i32,
+        height as i32,
+        channels as i32,
+      )
+    }
+    Ok(())
+  }
+
+  pub(crate) fn raw(&self) -> oasis_scene_t {
+    self.raw
+  }
+}
+
+impl Drop for Scene<'_> {
+  fn drop(&mut self) {
+    unsafe { oasis_scene_destroy(self.raw) }
+  }
+}
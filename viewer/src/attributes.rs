@@ -0,0 +1,135 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::mem;
+use std::path::{Path, PathBuf};
+
+extern crate gl;
+use self::gl::types::*;
+
+// Must match the `uuAttr` buffer binding in frag.glsl
+pub const ATTRIBUTE_SSBO_BINDING: GLuint = 4;
+
+const ATTRIBUTE_MAGIC: &[u8; 4] = b"OATR";
+const ATTRIBUTE_VERSION: u32 = 1;
+
+// One per-node attribute channel read from a .svattr sidecar
+pub struct AttributeChannel {
+  pub name: String,
+  pub values: Vec<f32>,
+  // Range of the non-NaN values, used to normalize the heatmap
+  pub min: f32,
+  pub max: f32,
+}
+
+// Default sidecar location: the model path with a .svattr extension
+pub fn sidecar_path(model: &Path) -> PathBuf {
+  model.with_extension("svattr")
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+  let mut buf = [0u8; 4];
+  reader.read_exact(&mut buf)?;
+  Ok(u32::from_le_bytes(buf))
+}
+
+// Loads `channel` from the attribute sidecar written by the builder
+pub fn load_channel<P: AsRef<Path>>(path: P, channel: &str, node_count: usize) -> Result<AttributeChannel, String> {
+  let path = path.as_ref();
+  let err = |e: std::io::Error| format!("Failed to read attributes '{}': {e}", path.display());
+
+  let mut reader = BufReader::new(File::open(path).map_err(err)?);
+
+  let mut magic = [0u8; 4];
+  reader.read_exact(&mut magic).map_err(err)?;
+  if &magic != ATTRIBUTE_MAGIC || read_u32(&mut reader).map_err(err)? != ATTRIBUTE_VERSION {
+    return Err(format!("'{}' is not a supported attribute file", path.display()));
+  }
+
+  let mut count = [0u8; 8];
+  reader.read_exact(&mut count).map_err(err)?;
+  let count = u64::from_le_bytes(count) as usize;
+  if count != node_count {
+    return Err(format!(
+      "'{}' has attributes for {} nodes but the pool has {}",
+      path.display(),
+      count,
+      node_count
+    ));
+  }
+
+  let channel_count = read_u32(&mut reader).map_err(err)? as usize;
+  let mut names = Vec::with_capacity(channel_count);
+  for _ in 0..channel_count {
+    let len = read_u32(&mut reader).map_err(err)? as usize;
+    let mut name = vec![0u8; len];
+    reader.read_exact(&mut name).map_err(err)?;
+    names.push(String::from_utf8_lossy(&name).into_owned());
+  }
+
+  let index = names.iter().position(|n| n == channel).ok_or_else(|| {
+    format!(
+      "Channel '{}' not found in '{}', available: {}",
+      channel,
+      path.display(),
+      names.join(", ")
+    )
+  })?;
+
+  // Skip the channels before the requested one
+  let stride = (count * mem::size_of::<f32>()) as u64;
+  std::io::copy(&mut (&mut reader).take(stride * index as u64), &mut std::io::sink()).map_err(err)?;
+
+  let mut bytes = vec![0u8; count * mem::size_of::<f32>()];
+  reader.read_exact(&mut bytes).map_err(err)?;
+  let values: Vec<f32> = bytes
+    .chunks_exact(4)
+    .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    .collect();
+
+  let (min, max) = values
+    .iter()
+    .filter(|v| !v.is_nan())
+    .fold((f32::MAX, f32::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+  let (min, max) = if min > max { (0.0, 1.0) } else { (min, max) };
+
+  Ok(AttributeChannel {
+    name: channel.to_string(),
+    values,
+    min,
+    max,
+  })
+}
+
+// Uploads the channel values and binds them to ATTRIBUTE_SSBO_BINDING
+pub fn create_attribute_ssbo(channel: &AttributeChannel) -> GLuint {
+  let mut ssbo: GLuint = 0;
+  unsafe {
+    gl::GenBuffers(1, &mut ssbo);
+    gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, ssbo);
+    gl::BufferData(
+      gl::SHADER_STORAGE_BUFFER,
+      (channel.values.len() * mem::size_of::<f32>()) as GLsizeiptr,
+      channel.values.as_ptr() as *const _,
+      gl::STATIC_DRAW,
+    );
+    gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, ATTRIBUTE_SSBO_BINDING, ssbo);
+    gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, 0);
+  }
+  ssbo
+}
@@ -0,0 +1,172 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use nalgebra_glm as glm;
+use oasis_render::Camera;
+use serde::{Deserialize, Serialize};
+
+const BOOKMARKS_VERSION: u32 = 1;
+
+// Bookmarks are numbered like the keys that recall them
+pub const BOOKMARK_SLOTS: u8 = 9;
+
+// Seconds a jump between bookmarks takes
+const FLIGHT_DURATION: f32 = 0.6;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct Bookmark {
+  pub position: [f32; 3],
+  pub yaw: f32,
+  pub pitch: f32,
+  pub fov: f32,
+}
+
+impl Bookmark {
+  pub fn of(camera: &Camera) -> Self {
+    Self {
+      position: [camera.position.x, camera.position.y, camera.position.z],
+      yaw: camera.yaw,
+      pitch: camera.pitch,
+      // Saving mid-zoom keeps the FOV the user was heading for
+      fov: camera.target_fov,
+    }
+  }
+
+  pub fn apply(&self, camera: &mut Camera) {
+    camera.position = glm::vec3(self.position[0], self.position[1], self.position[2]);
+    camera.yaw = self.yaw;
+    camera.pitch = self.pitch;
+    camera.fov = self.fov;
+    camera.target_fov = self.fov;
+    camera.update_vectors();
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct BookmarkFile {
+  version: u32,
+  bookmarks: BTreeMap<u8, Bookmark>,
+}
+
+// Camera bookmarks of one model, stored next to it in `<model>.bookmarks.json`
+pub struct Bookmarks {
+  path: PathBuf,
+  slots: BTreeMap<u8, Bookmark>,
+}
+
+pub fn sidecar_path(model: &Path) -> PathBuf {
+  let mut path = model.as_os_str().to_owned();
+  path.push(".bookmarks.json");
+  PathBuf::from(path)
+}
+
+impl Bookmarks {
+  // A missing file is an empty set. A broken one is reported and replaced on
+  // the next save rather than keeping the viewer from starting.
+  pub fn load(path: PathBuf) -> Self {
+    let slots = match fs::read_to_string(&path) {
+      Ok(text) => match serde_json::from_str::<BookmarkFile>(&text) {
+        Ok(file) if file.version == BOOKMARKS_VERSION => file.bookmarks,
+        Ok(file) => {
+          eprintln!("Ignoring bookmarks '{}': unsupported version {}", path.display(), file.version);
+          BTreeMap::new()
+        }
+        Err(e) => {
+          eprintln!("Ignoring bookmarks '{}': {}", path.display(), e);
+          BTreeMap::new()
+        }
+      },
+      Err(_) => BTreeMap::new(),
+    };
+    Self { path, slots }
+  }
+
+  pub fn len(&self) -> usize {
+    self.slots.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.slots.is_empty()
+  }
+
+  pub fn get(&self, slot: u8) -> Option<Bookmark> {
+    self.slots.get(&slot).copied()
+  }
+
+  // Saved bookmarks in slot order
+  pub fn iter(&self) -> impl Iterator<Item = (u8, Bookmark)> + '_ {
+    self.slots.iter().map(|(&slot, &bookmark)| (slot, bookmark))
+  }
+
+  // Stores the bookmark and rewrites the sidecar right away, so nothing is
+  // lost if the viewer is killed
+  pub fn set(&mut self, slot: u8, bookmark: Bookmark) -> Result<(), String> {
+    self.slots.insert(slot, bookmark);
+    let file = BookmarkFile {
+      version: BOOKMARKS_VERSION,
+      bookmarks: self.slots.clone(),
+    };
+    let json = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+    fs::write(&self.path, json).map_err(|e| format!("Failed to write bookmarks '{}': {}", self.path.display(), e))
+  }
+}
+
+// Smooth camera move from wherever it was to a bookmark
+pub struct Flight {
+  from: Bookmark,
+  to: Bookmark,
+  elapsed: f32,
+}
+
+impl Flight {
+  pub fn new(camera: &Camera, to: Bookmark) -> Self {
+    let mut from = Bookmark::of(camera);
+    from.fov = camera.fov;
+    // Turn the short way around instead of unwinding accumulated yaw
+    let turn = (to.yaw - from.yaw + 180.0).rem_euclid(360.0) - 180.0;
+    let to = Bookmark {
+      yaw: from.yaw + turn,
+      ..to
+    };
+    Self { from, to, elapsed: 0.0 }
+  }
+
+  // Moves the camera along the flight, returns false once it has arrived
+  pub fn step(&mut self, camera: &mut Camera, delta_time: f32) -> bool {
+    self.elapsed += delta_time;
+    let t = (self.elapsed / FLIGHT_DURATION).min(1.0);
+    let s = t * t * (3.0 - 2.0 * t);
+
+    let lerp = |a: f32, b: f32| a + (b - a) * s;
+    Bookmark {
+      position: [
+        lerp(self.from.position[0], self.to.position[0]),
+        lerp(self.from.position[1], self.to.position[1]),
+        lerp(self.from.position[2], self.to.position[2]),
+      ],
+      yaw: lerp(self.from.yaw, self.to.yaw),
+      pitch: lerp(self.from.pitch, self.to.pitch),
+      fov: lerp(self.from.fov, self.to.fov),
+    }
+    .apply(camera);
+
+    t < 1.0
+  }
+}
@@ -0,0 +1,290 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use image::{Rgb, RgbImage};
+
+// Largest dissimilarity (1 - SSIM) that still passes
+const DEFAULT_THRESHOLD: f64 = 0.01;
+
+// SSIM window size and stride in pixels
+const WINDOW: u32 = 8;
+const STRIDE: u32 = 4;
+
+// Per-channel difference above which a pixel counts as changed, absorbs
+// rounding noise between drivers
+const PIXEL_TOLERANCE: u8 = 2;
+
+pub struct DiffStats {
+  pub mean_abs: f64,
+  pub max_abs: u8,
+  pub changed_pixels: u64,
+  pub ssim: f64,
+}
+
+fn luma(p: &Rgb<u8>) -> f64 {
+  0.299 * p.0[0] as f64 + 0.587 * p.0[1] as f64 + 0.114 * p.0[2] as f64
+}
+
+// Mean SSIM over WINDOW x WINDOW luma windows (Wang et al. 2004 constants)
+fn ssim(a: &RgbImage, b: &RgbImage) -> f64 {
+  const C1: f64 = (0.01 * 255.0) * (0.01 * 255.0);
+  const C2: f64 = (0.03 * 255.0) * (0.03 * 255.0);
+
+  let (width, height) = a.dimensions();
+  let win_w = WINDOW.min(width);
+  let win_h = WINDOW.min(height);
+
+  let mut sum = 0.0;
+  let mut windows = 0u64;
+  let mut y = 0;
+  while y + win_h <= height {
+    let mut x = 0;
+    while x + win_w <= width {
+      let n = (win_w * win_h) as f64;
+      let (mut sa, mut sb, mut saa, mut sbb, mut sab) = (0.0, 0.0, 0.0, 0.0, 0.0);
+      for wy in y..y + win_h {
+        for wx in x..x + win_w {
+          let la = luma(a.get_pixel(wx, wy));
+          let lb = luma(b.get_pixel(wx, wy));
+          sa += la;
+          sb += lb;
+          saa += la * la;
+          sbb += lb * lb;
+          sab += la * lb;
+        }
+      }
+      let (ma, mb) = (sa / n, sb / n);
+      let va = saa / n - ma * ma;
+      let vb = sbb / n - mb * mb;
+      let cov = sab / n - ma * mb;
+
+      sum += ((2.0 * ma * mb + C1) * (2.0 * cov + C2)) / ((ma * ma + mb * mb + C1) * (va + vb + C2));
+      windows += 1;
+      x += STRIDE;
+    }
+    y += STRIDE;
+  }
+
+  if windows == 0 {
+    1.0
+  } else {
+    sum / windows as f64
+  }
+}
+
+// Same palette as Heat() in frag.glsl
+fn heat(x: f32) -> Rgb<u8> {
+  let x = x.clamp(0.0, 1.0) * 3.0;
+  let c = |k: f32| (((x - k).sin() * 0.5 + 0.5) * 255.0).round() as u8;
+  Rgb([c(1.0), c(2.0), c(3.0)])
+}
+
+// Compares two images of the same size, returning the statistics and a
+// heatmap of the largest channel difference per pixel
+pub fn diff_images(a: &RgbImage, b: &RgbImage) -> Result<(DiffStats, RgbImage), String> {
+  if a.dimensions() != b.dimensions() {
+    return Err(format!(
+      "Image sizes differ: {}x{} vs {}x{}",
+      a.width(),
+      a.height(),
+      b.width(),
+      b.height()
+    ));
+  }
+
+  let (width, height) = a.dimensions();
+  let mut diffs = Vec::with_capacity((width * height) as usize);
+  let mut total = 0u64;
+  let mut stats = DiffStats {
+    mean_abs: 0.0,
+    max_abs: 0,
+    changed_pixels: 0,
+    ssim: ssim(a, b),
+  };
+
+  for y in 0..height {
+    for x in 0..width {
+      let (pa, pb) = (a.get_pixel(x, y), b.get_pixel(x, y));
+      let mut pixel_max = 0u8;
+      for k in 0..3 {
+        let d = pa.0[k].abs_diff(pb.0[k]);
+        total += d as u64;
+        pixel_max = pixel_max.max(d);
+      }
+      if pixel_max > PIXEL_TOLERANCE {
+        stats.changed_pixels += 1;
+      }
+      stats.max_abs = stats.max_abs.max(pixel_max);
+      diffs.push(pixel_max);
+    }
+  }
+  stats.mean_abs = total as f64 / (width as f64 * height as f64 * 3.0).max(1.0) / 255.0;
+
+  // Scaled to the largest difference so small regressions are still visible
+  let scale = 1.0 / stats.max_abs.max(1) as f32;
+  let heatmap = RgbImage::from_fn(width, height, |x, y| {
+    let d = diffs[(y * width + x) as usize];
+    if d == 0 {
+      Rgb([0, 0, 0])
+    } else {
+      heat(d as f32 * scale)
+    }
+  });
+
+  Ok((stats, heatmap))
+}
+
+fn print_usage() -> ! {
+  eprintln!("Usage: ./viewer --diff-images <a.png> <b.png> [--out <heatmap.png>] [--threshold <1 - ssim>]");
+  eprintln!("Exits with 1 if the images differ by more than the threshold (default {}).", DEFAULT_THRESHOLD);
+  std::process::exit(2);
+}
+
+// Returns the process exit code: 0 on match, 1 above the threshold, 2 on errors
+pub fn run(mut args: impl Iterator<Item = OsString>) -> i32 {
+  let mut images: Option<(PathBuf, PathBuf)> = None;
+  let mut out: Option<PathBuf> = None;
+  let mut threshold = DEFAULT_THRESHOLD;
+
+  while let Some(arg) = args.next() {
+    match arg.to_string_lossy().as_ref() {
+      "--diff-images" => {
+        let a = PathBuf::from(args.next().unwrap_or_else(|| print_usage()));
+        let b = PathBuf::from(args.next().unwrap_or_else(|| print_usage()));
+        images = Some((a, b));
+      }
+      "--out" => out = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--threshold" => {
+        let value = args.next().unwrap_or_else(|| print_usage()).to_string_lossy().into_owned();
+        threshold = value.parse().unwrap_or_else(|_| {
+          eprintln!("Invalid threshold '{}'", value);
+          print_usage()
+        });
+      }
+      other => {
+        eprintln!("Unknown option in diff mode: {}", other);
+        print_usage();
+      }
+    }
+  }
+
+  let (path_a, path_b) = images.unwrap_or_else(|| print_usage());
+  let load = |path: &Path| {
+    image::open(path)
+      .map(|img| img.to_rgb8())
+      .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))
+  };
+  let result = load(&path_a)
+    .and_then(|a| load(&path_b).map(|b| (a, b)))
+    .and_then(|(a, b)| diff_images(&a, &b));
+  let (stats, heatmap) = match result {
+    Ok(result) => result,
+    Err(e) => {
+      eprintln!("{}", e);
+      return 2;
+    }
+  };
+
+  let dssim = 1.0 - stats.ssim;
+  println!("SSIM:           {:.5} (1 - SSIM = {:.5}, threshold {})", stats.ssim, dssim, threshold);
+  println!("Mean abs diff:  {:.5}", stats.mean_abs);
+  println!("Max abs diff:   {}/255", stats.max_abs);
+  println!("Changed pixels: {} (> {}/255)", stats.changed_pixels, PIXEL_TOLERANCE);
+
+  if let Some(out) = out {
+    match heatmap.save(&out) {
+      Ok(()) => println!("Wrote difference heatmap to '{}'.", out.display()),
+      Err(e) => {
+        eprintln!("Failed to write heatmap '{}': {}", out.display(), e);
+        return 2;
+      }
+    }
+  }
+
+  if dssim > threshold {
+    println!("FAIL: images differ above the threshold.");
+    1
+  } else {
+    println!("PASS");
+    0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use std::env;
+  use std::fs;
+
+  // Smooth gradient so every SSIM window has some variance
+  fn gradient(width: u32, height: u32) -> RgbImage {
+    RgbImage::from_fn(width, height, |x, y| Rgb([(x * 7) as u8, (y * 5) as u8, ((x + y) * 3) as u8]))
+  }
+
+  #[test]
+  fn identical_images_match() {
+    let a = gradient(32, 24);
+    let (stats, heatmap) = diff_images(&a, &a.clone()).unwrap();
+    assert_eq!(stats.ssim, 1.0);
+    assert_eq!((stats.mean_abs, stats.max_abs, stats.changed_pixels), (0.0, 0, 0));
+    assert!(heatmap.pixels().all(|p| p.0 == [0, 0, 0]));
+
+    let dir = env::temp_dir().join(format!("oasis-imgdiff-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("a.png");
+    a.save(&path).unwrap();
+    let args = ["--diff-images", path.to_str().unwrap(), path.to_str().unwrap()].map(OsString::from);
+    let code = run(args.into_iter());
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(code, 0);
+  }
+
+  #[test]
+  fn one_changed_pixel_is_counted() {
+    let a = gradient(16, 16);
+    let mut b = a.clone();
+    b.put_pixel(3, 9, Rgb([255, 255, 255]));
+    // Within the tolerance, not counted
+    let p = a.get_pixel(10, 2).0;
+    b.put_pixel(10, 2, Rgb([p[0] + PIXEL_TOLERANCE, p[1], p[2]]));
+
+    let (stats, heatmap) = diff_images(&a, &b).unwrap();
+    assert_eq!(stats.changed_pixels, 1);
+    assert_eq!(stats.max_abs, 255 - a.get_pixel(3, 9).0.iter().min().unwrap());
+    assert!(stats.ssim < 1.0);
+    assert_ne!(heatmap.get_pixel(3, 9).0, [0, 0, 0]);
+    assert_eq!(heatmap.get_pixel(0, 0).0, [0, 0, 0]);
+  }
+
+  #[test]
+  fn different_sizes_are_rejected() {
+    assert!(diff_images(&gradient(16, 16), &gradient(16, 15)).is_err());
+  }
+
+  #[test]
+  fn images_below_one_window_still_compare() {
+    let a = gradient(5, 3);
+    let mut b = a.clone();
+    b.put_pixel(2, 1, Rgb([200, 0, 0]));
+    let (stats, _) = diff_images(&a, &b).unwrap();
+    assert!(stats.ssim.is_finite() && stats.ssim < 1.0);
+    assert_eq!(diff_images(&a, &a).unwrap().0.ssim, 1.0);
+  }
+}
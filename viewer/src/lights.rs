@@ -0,0 +1,171 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::fs;
+use std::mem;
+use std::path::Path;
+
+extern crate gl;
+use self::gl::types::*;
+
+use serde::Deserialize;
+
+// Must match MAX_LIGHTS in frag.glsl
+pub const MAX_LIGHTS: usize = 16;
+
+// Uniform block binding used by the `uuLights` block in frag.glsl
+pub const LIGHTS_UBO_BINDING: GLuint = 2;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LightKind {
+  Point,
+  Spot,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Light {
+  #[serde(rename = "type")]
+  pub kind: LightKind,
+  pub position: [f32; 3],
+  #[serde(default = "default_direction")]
+  pub direction: [f32; 3],
+  #[serde(default = "default_color")]
+  pub color: [f32; 3],
+  #[serde(default = "default_intensity")]
+  pub intensity: f32,
+  // Distance at which the light has fully faded out
+  #[serde(default = "default_range")]
+  pub range: f32,
+  // Spot cone angles in degrees (full intensity inside `inner_angle`)
+  #[serde(default = "default_inner_angle")]
+  pub inner_angle: f32,
+  #[serde(default = "default_outer_angle")]
+  pub outer_angle: f32,
+  #[serde(default = "default_cast_shadows")]
+  pub cast_shadows: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LightRig {
+  #[serde(default = "default_ambient")]
+  pub ambient: [f32; 3],
+  pub lights: Vec<Light>,
+}
+
+fn default_direction() -> [f32; 3] { [0.0, -1.0, 0.0] }
+fn default_color() -> [f32; 3] { [1.0, 1.0, 1.0] }
+fn default_intensity() -> f32 { 1.0 }
+fn default_range() -> f32 { 1.0 }
+fn default_inner_angle() -> f32 { 20.0 }
+fn default_outer_angle() -> f32 { 30.0 }
+fn default_cast_shadows() -> bool { true }
+fn default_ambient() -> [f32; 3] { [0.1, 0.1, 0.1] }
+
+impl LightRig {
+  pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)
+      .map_err(|e| format!("Failed to read light rig '{}': {e}", path.display()))?;
+    let mut rig: LightRig = serde_json::from_str(&text)
+      .map_err(|e| format!("Failed to parse light rig '{}': {e}", path.display()))?;
+
+    if rig.lights.len() > MAX_LIGHTS {
+      eprintln!(
+        "Light rig has {} lights, only the first {} will be used.",
+        rig.lights.len(),
+        MAX_LIGHTS
+      );
+      rig.lights.truncate(MAX_LIGHTS);
+    }
+
+    Ok(rig)
+  }
+}
+
+// std140 layout of a single light, see `struct Light` in frag.glsl
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+struct LightStd140 {
+  position_range: [f32; 4],
+  color_intensity: [f32; 4],
+  direction_kind: [f32; 4],
+  cone_shadow: [f32; 4],
+}
+
+// std140 layout of the `uuLights` uniform block
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+struct LightBlockStd140 {
+  count: [u32; 4],
+  ambient: [f32; 4],
+  lights: [LightStd140; MAX_LIGHTS],
+}
+
+fn pack_light(light: &Light) -> LightStd140 {
+  let kind = match light.kind {
+    LightKind::Point => 0.0,
+    LightKind::Spot => 1.0,
+  };
+
+  let d = light.direction;
+  let len = (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt().max(1e-6);
+
+  LightStd140 {
+    position_range: [light.position[0], light.position[1], light.position[2], light.range],
+    color_intensity: [light.color[0], light.color[1], light.color[2], light.intensity],
+    direction_kind: [d[0] / len, d[1] / len, d[2] / len, kind],
+    cone_shadow: [
+      light.inner_angle.to_radians().cos(),
+      light.outer_angle.to_radians().cos(),
+      if light.cast_shadows { 1.0 } else { 0.0 },
+      0.0,
+    ],
+  }
+}
+
+// Creates the light UBO and binds it to LIGHTS_UBO_BINDING.
+// Passing `None` uploads an empty rig, which leaves the scene unlit.
+pub fn create_light_ubo(rig: Option<&LightRig>) -> GLuint {
+  let mut block = LightBlockStd140 {
+    count: [0; 4],
+    ambient: [0.0; 4],
+    lights: [LightStd140::default(); MAX_LIGHTS],
+  };
+
+  if let Some(rig) = rig {
+    block.count[0] = rig.lights.len() as u32;
+    block.ambient = [rig.ambient[0], rig.ambient[1], rig.ambient[2], 0.0];
+    for (dst, light) in block.lights.iter_mut().zip(rig.lights.iter()) {
+      *dst = pack_light(light);
+    }
+  }
+
+  let mut ubo: GLuint = 0;
+  unsafe {
+    gl::GenBuffers(1, &mut ubo);
+    gl::BindBuffer(gl::UNIFORM_BUFFER, ubo);
+    gl::BufferData(
+      gl::UNIFORM_BUFFER,
+      mem::size_of::<LightBlockStd140>() as GLsizeiptr,
+      &block as *const LightBlockStd140 as *const _,
+      gl::STATIC_DRAW,
+    );
+    gl::BindBufferBase(gl::UNIFORM_BUFFER, LIGHTS_UBO_BINDING, ubo);
+    gl::BindBuffer(gl::UNIFORM_BUFFER, 0);
+  }
+  ubo
+}
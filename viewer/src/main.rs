@@ -19,206 +19,190 @@ extern crate glfw;
 use self::glfw::{Context, Key, Action};
 
 extern crate gl;
-use self::gl::types::*;
 
 use std::sync::mpsc::Receiver;
-use std::ffi::CString;
-use std::ptr;
-use std::str;
+use std::ffi::OsString;
 use std::mem;
-use std::os::raw::c_void;
-use std::slice;
 use std::env;
+use std::path::PathBuf;
+use std::time::Instant;
 
-use oasis_bindings::*;
+use oasis::NodePool;
+use oasis_render::{Camera, CameraMovement, PoolGpuCache, PoolId, Renderer, DEFAULT_FOV};
 
-// Camera
-use nalgebra_glm as glm;
-
-// settings
-const SCR_WIDTH: u32 = 800;
-const SCR_HEIGHT: u32 = 600;
+mod attributes;
 
+mod bookmarks;
+use bookmarks::{Bookmark, Bookmarks, Flight};
 
-pub struct Camera {
-  pub position: glm::Vec3,
-  pub front: glm::Vec3,
-  pub up: glm::Vec3,
-  pub right: glm::Vec3,
-  pub world_up: glm::Vec3,
-  pub yaw: f32,
-  pub pitch: f32,
-  pub fov: f32,
-  pub aspect_ratio: f32,
-  pub near: f32,
-  pub far: f32,
-}
+mod imgdiff;
 
-impl Camera {
-  pub fn new(position: glm::Vec3, aspect_ratio: f32) -> Self {
-    let mut camera = Self {
-      position,
-      front: glm::vec3(0.0, 0.0, -1.0),
-      up: glm::vec3(0.0, 1.0, 0.0),
-      right: glm::vec3(1.0, 0.0, 0.0),
-      world_up: glm::vec3(0.0, 1.0, 0.0),
-      yaw: -90.0,
-      pitch: 0.0,
-      fov: 45.0,
-      aspect_ratio,
-      near: 0.1,
-      far: 100.0,
-    };
-    camera.update_vectors();
-    camera
-  }
+mod lights;
+use lights::LightRig;
 
-  pub fn get_view_matrix(&self) -> glm::Mat4 {
-    glm::look_at(&self.position, &(self.position + self.front), &self.up)
-  }
+mod post;
+use post::{DofSettings, PostProcess};
 
-  pub fn get_proj_matrix(&self) -> glm::Mat4 {
-    glm::perspective(self.aspect_ratio, self.fov.to_radians(), self.near, self.far)
-  }
+mod quality;
+use quality::QualityController;
 
-  pub fn get_view_proj_matrix(&self) -> glm::Mat4 {
-    self.get_proj_matrix() * self.get_view_matrix()
-  }
+mod timeline;
+use timeline::{CameraPath, Timeline};
 
-  pub fn update_vectors(&mut self) {
-    let yaw_radians = self.yaw.to_radians();
-    let pitch_radians = self.pitch.to_radians();
+// Camera
+use nalgebra_glm as glm;
 
-    let front = glm::vec3(
-      yaw_radians.cos() * pitch_radians.cos(),
-      pitch_radians.sin(),
-      yaw_radians.sin() * pitch_radians.cos(),
-    );
-    self.front = glm::normalize(&front);
-    self.right = glm::normalize(&glm::cross(&self.front, &self.world_up));
-    self.up = glm::normalize(&glm::cross(&self.right, &self.front));
-  }
+// settings
+const WINDOW_TITLE: &str = "Oasis Viewer (Rust)";
+const SCR_WIDTH: u32 = 800;
+const SCR_HEIGHT: u32 = 600;
 
-  pub fn process_mouse_movement(&mut self, x_offset: f32, y_offset: f32, constrain_pitch: bool) {
-    let sensitivity = 0.1;
-    self.yaw += x_offset * sensitivity;
-    self.pitch += y_offset * sensitivity;
+// Seconds without input before dropping to IDLE_FPS
+const IDLE_TIMEOUT: f64 = 3.0;
+const IDLE_FPS: f64 = 10.0;
 
-    if constrain_pitch {
-      if self.pitch > 89.0 {
-        self.pitch = 89.0;
-      }
-      if self.pitch < -89.0 {
-        self.pitch = -89.0;
-      }
-    }
 
-    self.update_vectors();
-  }
+// Detects the frame a key goes down, for toggles
+#[derive(Default)]
+struct KeyEdge {
+  was_pressed: bool,
+}
 
-  pub fn process_keyboard(&mut self, direction: CameraMovement, delta_time: f32) {
-    let velocity = 2.5 * delta_time;
-    match direction {
-      CameraMovement::Forward => self.position += self.front * velocity,
-      CameraMovement::Backward => self.position -= self.front * velocity,
-      CameraMovement::Left => self.position -= self.right * velocity,
-      CameraMovement::Right => self.position += self.right * velocity,
-    }
+impl KeyEdge {
+  fn pressed(&mut self, window: &glfw::Window, key: Key) -> bool {
+    let is_pressed = window.get_key(key) == Action::Press;
+    let edge = is_pressed && !self.was_pressed;
+    self.was_pressed = is_pressed;
+    edge
   }
 }
 
-pub enum CameraMovement {
-  Forward,
-  Backward,
-  Left,
-  Right,
+struct ViewerArgs {
+  filename: PathBuf,
+  lights: Option<PathBuf>,
+  max_fps: Option<f64>,
+  attributes: Option<PathBuf>,
+  attr_channel: Option<String>,
+  fast_load: bool,
+  stream_upload: bool,
+  upload_budget_mb: usize,
+  target_ms: Option<f32>,
 }
 
-fn compile_shader(src: &str, shader_type: GLenum) -> GLuint {
-  let shader = unsafe { gl::CreateShader(shader_type) };
-  let c_str = CString::new(src).unwrap();
-  unsafe {
-    gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
-    gl::CompileShader(shader);
-
-    let mut success = gl::FALSE as GLint;
-    gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut success);
-    if success != gl::TRUE as GLint {
-      let mut info_log = vec![0; 512];
-      gl::GetShaderInfoLog(shader, 512, ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
-      panic!(
-        "ERROR::SHADER::{:?}::COMPILATION_FAILED\n{}",
-        shader_type,
-        str::from_utf8(&info_log).unwrap()
-      );
-    }
-  }
-  shader
+fn print_usage() -> ! {
+  eprintln!("Usage: ./viewer <model.svdag> [--lights rig.json] [--max-fps N] [--attr-channel <name> [--attributes <file.svattr>]] [--fast-load] [--stream-upload [--upload-budget <MB>]] [--target-ms <ms>]");
+  eprintln!("       ./viewer --diff-images <a.png> <b.png> [--out <heatmap.png>] [--threshold <1 - ssim>]");
+  eprintln!("--target-ms lowers render scale, traversal depth and then shadows to hold a GPU frame time.");
+  eprintln!("The viewer has no ambient occlusion pass, so shadows are the last thing it turns off.");
+  std::process::exit(1);
 }
 
-fn link_program(vs: GLuint, fs: GLuint) -> GLuint {
-  let program = unsafe { gl::CreateProgram() };
-  unsafe {
-    gl::AttachShader(program, vs);
-    gl::AttachShader(program, fs);
-    gl::LinkProgram(program);
+// Option values that are plain text rather than paths
+fn next_text(args: &mut impl Iterator<Item = OsString>) -> String {
+  args.next().unwrap_or_else(|| print_usage()).to_string_lossy().into_owned()
+}
 
-    let mut success = gl::FALSE as GLint;
-    gl::GetProgramiv(program, gl::LINK_STATUS, &mut success);
-    if success != gl::TRUE as GLint {
-      let mut info_log = vec![0; 512];
-      gl::GetProgramInfoLog(program, 512, ptr::null_mut(), info_log.as_mut_ptr() as *mut GLchar);
-      panic!("ERROR::PROGRAM::LINKING_FAILED\n{}", str::from_utf8(&info_log).unwrap());
+fn parse_args() -> ViewerArgs {
+  let mut filename = None;
+  let mut lights = None;
+  let mut max_fps = None;
+  let mut attributes = None;
+  let mut attr_channel = None;
+  let mut fast_load = false;
+  let mut stream_upload = false;
+  let mut upload_budget_mb = oasis_render::DEFAULT_BUDGET_MB;
+  let mut target_ms = None;
+
+  // Read as OsString so model paths that aren't valid UTF-8 still load
+  let mut args = env::args_os().skip(1);
+  while let Some(arg) = args.next() {
+    match arg.to_string_lossy().as_ref() {
+      "--lights" => lights = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--max-fps" => {
+        let value = next_text(&mut args);
+        let fps = match value.trim().parse::<f64>() {
+          Ok(fps) if fps > 0.0 && fps.is_finite() => fps,
+          _ => {
+            eprintln!("Error: --max-fps must be a positive number, got '{}'", value);
+            if value.contains(',') {
+              eprintln!("Hint: use '.' as the decimal separator");
+            }
+            print_usage();
+          }
+        };
+        max_fps = Some(fps);
+      }
+      "--attributes" => attributes = Some(PathBuf::from(args.next().unwrap_or_else(|| print_usage()))),
+      "--attr-channel" => attr_channel = Some(next_text(&mut args)),
+      "--fast-load" => fast_load = true,
+      "--stream-upload" => stream_upload = true,
+      "--upload-budget" => {
+        let value = next_text(&mut args);
+        upload_budget_mb = match value.trim().parse::<usize>() {
+          Ok(mb) if mb > 0 => mb,
+          _ => {
+            eprintln!("Error: --upload-budget must be a whole number of MB above 0, got '{}'", value);
+            print_usage();
+          }
+        };
+      }
+      "--target-ms" => {
+        let value = next_text(&mut args);
+        let ms = match value.trim().parse::<f32>() {
+          Ok(ms) if ms > 0.0 && ms.is_finite() => ms,
+          _ => {
+            eprintln!("Error: --target-ms must be a positive number, got '{}'", value);
+            if value.contains(',') {
+              eprintln!("Hint: use '.' as the decimal separator");
+            }
+            print_usage();
+          }
+        };
+        target_ms = Some(ms);
+      }
+      option if option.starts_with("--") => {
+        eprintln!("Unknown option: {}", option);
+        print_usage();
+      }
+      _ if filename.is_none() => filename = Some(PathBuf::from(arg)),
+      _ => print_usage(),
     }
-
-    gl::DeleteShader(vs);
-    gl::DeleteShader(fs);
   }
-  program
-}
-
-fn create_fullscreen_quad_vao() -> GLuint {
-  let vertices: [f32; 12] = [
-    -1.0, -1.0, 0.0,
-     1.0, -1.0, 0.0,
-    -1.0,  1.0, 0.0,
-     1.0,  1.0, 0.0,
-  ];
-  let (mut vbo, mut vao) = (0, 0);
-  unsafe {
-    gl::GenVertexArrays(1, &mut vao);
-    gl::GenBuffers(1, &mut vbo);
-    gl::BindVertexArray(vao);
-
-    gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-    gl::BufferData(gl::ARRAY_BUFFER,
-      (vertices.len() * mem::size_of::<f32>()) as isize,
-      vertices.as_ptr() as *const _,
-      gl::STATIC_DRAW
-    );
-
-    gl::VertexAttribPointer(0, 3, gl::FLOAT, gl::FALSE, 0, ptr::null());
-    gl::EnableVertexAttribArray(0);
 
-    gl::BindBuffer(gl::ARRAY_BUFFER, 0);
-    gl::BindVertexArray(0);
+  ViewerArgs {
+    filename: filename.unwrap_or_else(|| print_usage()),
+    lights,
+    max_fps,
+    attributes,
+    attr_channel,
+    fast_load,
+    stream_upload,
+    upload_budget_mb,
+    target_ms,
   }
-  vao
 }
 
 pub fn main() {
-  let args: Vec<String> = env::args().collect();
-
-  if args.len() < 2 {
-    eprintln!("Usage: ./viewer <model.obj>");
-    std::process::exit(1);
+  // Comparing screenshots needs no window or pool
+  if env::args_os().any(|arg| arg == "--diff-images") {
+    std::process::exit(imgdiff::run(env::args_os().skip(1)));
   }
 
-  let filename = &args[1];
+  let args = parse_args();
+  let filename = &args.filename;
+
+  let light_rig = args.lights.as_ref().map(|path| {
+    LightRig::load(path).unwrap_or_else(|e| {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    })
+  });
+  if let Some(ref rig) = light_rig {
+    println!("Loaded light rig with {} lights.", rig.lights.len());
+  }
 
-  // Convert the obj_file path to a CString
-  let c_filename = CString::new(filename.as_str()).unwrap_or_else(|_| {
-    eprintln!("Invalid filename: contains a null byte.");
+  let node_pool = NodePool::deserialize(filename).unwrap_or_else(|e| {
+    eprintln!("Failed to load node pool '{}': {}", filename.display(), e);
     std::process::exit(1);
   });
 
@@ -233,13 +217,15 @@ pub fn main() {
   let height: u32 = SCR_HEIGHT;
   
   // GLFW window creation
-  let (mut window, events) = glfw.create_window(width, height, "Oasis Viewer (Rust)", glfw::WindowMode::Windowed)
+  let (mut window, events) = glfw.create_window(width, height, WINDOW_TITLE, glfw::WindowMode::Windowed)
     .expect("Failed to create GLFW window");
 
   window.make_current();
   window.set_key_polling(true);
   window.set_framebuffer_size_polling(true);
   window.set_cursor_pos_polling(true);
+  window.set_mouse_button_polling(true);
+  window.set_scroll_polling(true);
   glfw.set_swap_interval(glfw::SwapInterval::Sync(1)); // Enable V-Sync
 
   // Load all OpenGL function pointers
@@ -248,48 +234,84 @@ pub fn main() {
 
 
 
-  let handle = unsafe { oasis_node_pool_deserialize(c_filename.as_ptr()) };
-  if handle.is_null() {
-    panic!("Failed to deserialize node pool.");
-  }
-  
-  let pool_ptr = unsafe { oasis_node_pool_get(handle) };
-  if pool_ptr.is_null() {
-    panic!("Failed to get node pool.");
-  }
-  
-  let pool = unsafe { &*pool_ptr };
-  println!("Node count: {}", pool.count);
-  
-  let nodes = unsafe {
-    slice::from_raw_parts(pool.nodes, pool.count as usize)
-  };
+  let nodes = node_pool.as_slice();
   println!("Loaded {} nodes from C.", nodes.len());
 
-  const VERTEX_SHADER_SOURCE: &str = include_str!("vert.glsl");
-  const FRAGMENT_SHADER_SOURCE: &str = include_str!("frag.glsl");
+  if nodes.is_empty() {
+    eprintln!("Node pool '{}' is empty.", filename.display());
+    std::process::exit(1);
+  }
 
-  let vs = compile_shader(&VERTEX_SHADER_SOURCE, gl::VERTEX_SHADER);
-  let fs = compile_shader(&FRAGMENT_SHADER_SOURCE, gl::FRAGMENT_SHADER);
-  let shader_program = link_program(vs, fs);
-  
-  let vao = create_fullscreen_quad_vao();
+  // Skippable for trusted files since it touches every node once
+  if !args.fast_load {
+    if let Err(e) = oasis::format::validate_children(nodes) {
+      eprintln!("Invalid node pool '{}': {}", filename.display(), e);
+      std::process::exit(1);
+    }
+  }
 
-  let mut node_ssbo: GLuint = 0;
-  unsafe {
-    gl::GenBuffers(1, &mut node_ssbo);
-    gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, node_ssbo);
-    gl::BufferData(
-      gl::SHADER_STORAGE_BUFFER,
-      (nodes.len() * std::mem::size_of::<node_t>()) as GLsizeiptr,
-      nodes.as_ptr() as *const c_void,
-      gl::STATIC_DRAW,
+  // Additional views on this context would acquire the same pool from the
+  // cache and share its SSBO instead of uploading it again
+  let mut pool_cache = PoolGpuCache::new();
+  let pool_id = PoolId::next();
+  let pool_bytes = mem::size_of_val(nodes);
+  let upload_start = Instant::now();
+  let (pool, mut upload) = if args.stream_upload {
+    pool_cache.acquire_streamed(pool_id, nodes, args.upload_budget_mb)
+  } else {
+    (pool_cache.acquire(pool_id, nodes), None)
+  };
+  let mut renderer = Renderer::new(pool, width, height);
+  if upload.is_none() {
+    let ms = upload_start.elapsed().as_secs_f32() * 1000.0;
+    renderer.events().pool_uploaded(nodes.len(), pool_bytes, ms);
+  }
+
+  let light_ubo = lights::create_light_ubo(light_rig.as_ref());
+
+  let attr_channel = args.attr_channel.as_ref().map(|channel| {
+    let path = args
+      .attributes
+      .clone()
+      .unwrap_or_else(|| attributes::sidecar_path(filename));
+    attributes::load_channel(&path, channel, nodes.len()).unwrap_or_else(|e| {
+      eprintln!("{}", e);
+      std::process::exit(1);
+    })
+  });
+  let attr_ssbo = attr_channel.as_ref().map(attributes::create_attribute_ssbo);
+  if let Some(ref channel) = attr_channel {
+    println!(
+      "Attribute channel '{}' range [{}, {}], press H to toggle the heatmap.",
+      channel.name, channel.min, channel.max
     );
-    gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 3, node_ssbo);
-    gl::BindBuffer(gl::SHADER_STORAGE_BUFFER, 0);
   }
+  let mut show_heatmap = attr_channel.is_some();
+
+  let mut post = PostProcess::new(width, height);
+  let mut dof = DofSettings::default();
+
+  // Trades render scale, traversal depth and shadows for a steady frame time
+  let mut quality = args.target_ms.map(|target| {
+    println!("Adaptive quality targeting {} ms of GPU time per frame.", target);
+    QualityController::new(target)
+  });
+  let mut title = WINDOW_TITLE.to_string();
 
   let mut camera = Camera::new(glm::vec3(0.0, 0.0, 3.0), width as f32 / height as f32);
+
+  let mut bookmarks = Bookmarks::load(bookmarks::sidecar_path(filename));
+  if !bookmarks.is_empty() {
+    println!("Loaded {} bookmarks, press 1-9 to jump to them.", bookmarks.len());
+  }
+  let mut flight: Option<Flight> = None;
+
+  // Plays the bookmarks back in slot order as a camera path
+  let mut timeline = Timeline::default();
+  let mut camera_path = CameraPath::from_bookmarks(&bookmarks);
+  if let Some(ref path) = camera_path {
+    println!("Camera path through {} bookmarks ({:.1} s), press Space to play it.", bookmarks.len(), path.duration());
+  }
   
   let mut last_x = SCR_WIDTH as f32 / 2.0;
   let mut last_y = SCR_HEIGHT as f32 / 2.0;
@@ -297,18 +319,21 @@ pub fn main() {
   
   let mut last_frame: f32 = 0.0;
 
-  let loc_u_pos = CString::new("uPos").unwrap();
-  let loc_u_viewproj = CString::new("uViewProj").unwrap();
-  let loc_u_width = CString::new("uWidth").unwrap();
-  let loc_u_height = CString::new("uHeight").unwrap();
-  
-  let u_pos_loc = unsafe { gl::GetUniformLocation(shader_program, loc_u_pos.as_ptr()) };
-  let u_viewproj_loc = unsafe { gl::GetUniformLocation(shader_program, loc_u_viewproj.as_ptr()) };
-  let u_width_loc = unsafe { gl::GetUniformLocation(shader_program, loc_u_width.as_ptr()) };
-  let u_height_loc = unsafe { gl::GetUniformLocation(shader_program, loc_u_height.as_ptr()) };
-  
-  let mut tab_pressed_last_frame = false; 
+  let mut tab_key = KeyEdge::default();
+  let mut heatmap_key = KeyEdge::default();
+  let mut dof_key = KeyEdge::default();
+  let mut focus_key = KeyEdge::default();
+  let mut beam_key = KeyEdge::default();
+  let mut debug_key = KeyEdge::default();
+  let mut bookmark_keys: [KeyEdge; bookmarks::BOOKMARK_SLOTS as usize] = Default::default();
+  let mut play_key = KeyEdge::default();
+  let mut step_back_key = KeyEdge::default();
+  let mut step_forward_key = KeyEdge::default();
+  let mut rewind_key = KeyEdge::default();
+  let mut loop_key = KeyEdge::default();
   let mut cursor_disabled = true;
+
+  let mut last_input_time = glfw.get_time();
   
   // Render loop
   while !window.should_close() {
@@ -317,10 +342,12 @@ pub fn main() {
     last_frame = current_frame;
 
     // Events
-    process_events(&mut window, &events);
+    if process_events(&mut window, &events) {
+      last_input_time = glfw.get_time();
+    }
 
     // Toggle cursor mode with Tab key
-    if window.get_key(Key::Tab) == Action::Press && !tab_pressed_last_frame {
+    if tab_key.pressed(&window, Key::Tab) {
       cursor_disabled = !cursor_disabled;
       window.set_cursor_mode(if cursor_disabled {
         glfw::CursorMode::Disabled
@@ -329,7 +356,83 @@ pub fn main() {
       });
       first_mouse = true; // reset on mode change
     }
-    tab_pressed_last_frame = window.get_key(Key::Tab) == Action::Press;
+
+    // Toggle the attribute heatmap with H
+    if heatmap_key.pressed(&window, Key::H) && attr_channel.is_some() {
+      show_heatmap = !show_heatmap;
+    }
+
+    // Depth of field: F toggles, G toggles autofocus, [ ] aperture, , . focus distance
+    if dof_key.pressed(&window, Key::F) {
+      dof.enabled = !dof.enabled;
+      println!("Depth of field {}", if dof.enabled { "on" } else { "off" });
+    }
+    if focus_key.pressed(&window, Key::G) {
+      dof.auto_focus = !dof.auto_focus;
+      println!("Autofocus {}", if dof.auto_focus { "on" } else { "off" });
+    }
+    if window.get_key(Key::LeftBracket) == Action::Press {
+      dof.aperture = (dof.aperture - delta_time).max(0.0);
+    }
+    if window.get_key(Key::RightBracket) == Action::Press {
+      dof.aperture = (dof.aperture + delta_time).min(4.0);
+    }
+    if window.get_key(Key::Comma) == Action::Press {
+      dof.auto_focus = false;
+      dof.focus_distance = (dof.focus_distance * (1.0 - delta_time)).max(0.01);
+    }
+    if window.get_key(Key::Period) == Action::Press {
+      dof.auto_focus = false;
+      dof.focus_distance *= 1.0 + delta_time;
+    }
+
+    // B toggles the beam pre-pass, to compare against plain traversal
+    if beam_key.pressed(&window, Key::B) {
+      renderer.beam_enabled = !renderer.beam_enabled;
+      println!("Beam pre-pass {}", if renderer.beam_enabled { "on" } else { "off" });
+    }
+
+    // I toggles the traversal iteration heatmap
+    if debug_key.pressed(&window, Key::I) {
+      renderer.debug_iterations = !renderer.debug_iterations;
+    }
+
+    // FOV zoom: Z in, X out, C reset
+    if window.get_key(Key::Z) == Action::Press {
+      camera.zoom(-60.0 * delta_time);
+    }
+    if window.get_key(Key::X) == Action::Press {
+      camera.zoom(60.0 * delta_time);
+    }
+    if window.get_key(Key::C) == Action::Press {
+      camera.target_fov = DEFAULT_FOV;
+    }
+    // Bookmarks: Ctrl+1..9 saves the camera, 1..9 flies back to it
+    const BOOKMARK_KEYS: [Key; bookmarks::BOOKMARK_SLOTS as usize] = [
+      Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+    ];
+    let ctrl = window.get_key(Key::LeftControl) == Action::Press || window.get_key(Key::RightControl) == Action::Press;
+    for (index, key) in BOOKMARK_KEYS.iter().enumerate() {
+      if !bookmark_keys[index].pressed(&window, *key) {
+        continue;
+      }
+      let slot = index as u8 + 1;
+      if ctrl {
+        match bookmarks.set(slot, Bookmark::of(&camera)) {
+          Ok(()) => {
+            println!("Saved bookmark {}", slot);
+            camera_path = CameraPath::from_bookmarks(&bookmarks);
+          }
+          Err(e) => eprintln!("{}", e),
+        }
+      } else if let Some(bookmark) = bookmarks.get(slot) {
+        flight = Some(Flight::new(&camera, bookmark));
+      } else {
+        println!("Bookmark {} is empty, press Ctrl+{} to save one", slot, slot);
+      }
+    }
+
+    let mut animating = camera.update_fov(delta_time);
 
     // Camera Movement
     if window.get_key(Key::W) == Action::Press {
@@ -362,37 +465,148 @@ pub fn main() {
 
     camera.process_mouse_movement(xoffset, yoffset, true);
 
+    // A bookmark flight overrides manual movement until it arrives
+    if let Some(ref mut active) = flight {
+      if active.step(&mut camera, delta_time) {
+        animating = true;
+      } else {
+        flight = None;
+      }
+    }
+
+    // Timeline: Space plays/pauses, Left/Right step a frame, Shift+Left/Right
+    // scrub, Home rewinds, L cycles the loop mode
+    let duration = camera_path.as_ref().map(CameraPath::duration);
+    let time_before = timeline.time;
+    if play_key.pressed(&window, Key::Space) {
+      timeline.toggle(duration);
+    }
+    if loop_key.pressed(&window, Key::L) {
+      timeline.loop_mode = timeline.loop_mode.next();
+      println!("Timeline loop mode: {}", timeline.loop_mode.name());
+    }
+    if rewind_key.pressed(&window, Key::Home) {
+      timeline.rewind();
+    }
+    let shift = window.get_key(Key::LeftShift) == Action::Press || window.get_key(Key::RightShift) == Action::Press;
+    let step_back = step_back_key.pressed(&window, Key::Left);
+    let step_forward = step_forward_key.pressed(&window, Key::Right);
+    if shift {
+      if window.get_key(Key::Left) == Action::Press {
+        timeline.seek(timeline.time - timeline::SCRUB_RATE * delta_time, duration);
+      }
+      if window.get_key(Key::Right) == Action::Press {
+        timeline.seek(timeline.time + timeline::SCRUB_RATE * delta_time, duration);
+      }
+    } else if step_back {
+      timeline.step_frames(-1, duration);
+    } else if step_forward {
+      timeline.step_frames(1, duration);
+    }
+    timeline.advance(delta_time, duration);
+    // The path only takes the camera while the timeline moves, paused the
+    // camera is free again
+    if timeline.playing || timeline.time != time_before {
+      animating = true;
+      if let Some(ref path) = camera_path {
+        path.sample(timeline.time).apply(&mut camera);
+        flight = None;
+      }
+    }
+    renderer.time = timeline.time;
+
+    // Stream the next pages in and show how far along the pool is
+    if let Some(ref mut active) = upload {
+      active.step();
+      if active.is_done() {
+        upload = None;
+        let ms = upload_start.elapsed().as_secs_f32() * 1000.0;
+        renderer.events().pool_uploaded(nodes.len(), pool_bytes, ms);
+      } else {
+        animating = true;
+      }
+    }
+
+    // No overlay to draw into, the title shows the quality level and progress
+    let mut next_title = WINDOW_TITLE.to_string();
+    if let Some(ref controller) = quality {
+      next_title += &format!(" - {}", controller.label());
+    }
+    if let Some(ref active) = upload {
+      next_title += &format!(" - loading {:.0}%", active.progress() * 100.0);
+    }
+    if timeline.playing || timeline.time > 0.0 {
+      next_title += &format!(" - {}", timeline.label(duration));
+    }
+    if next_title != title {
+      window.set_title(&next_title);
+      title = next_title;
+    }
+
     // Render
+    renderer.begin_frame();
+    let (render_width, render_height) = post.render_size();
+    renderer.draw_beam(&camera, render_width, render_height);
+    post.bind_target();
     unsafe {
-      gl::ClearColor(0.2, 0.3, 0.3, 1.0);
-      gl::Clear(gl::COLOR_BUFFER_BIT);
-  
-      gl::Uniform3f(u_pos_loc, camera.position.x, camera.position.y, camera.position.z);
-      let inv_view_proj = glm::inverse(&camera.get_view_proj_matrix());
-      gl::UniformMatrix4fv(u_viewproj_loc, 1, gl::FALSE, inv_view_proj.as_ptr());
-      gl::Uniform1ui(u_width_loc, width);
-      gl::Uniform1ui(u_height_loc, height);
-      gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, 3, node_ssbo);
-
-      // Draw the fullscreen quad
-      gl::UseProgram(shader_program);
-      gl::BindVertexArray(vao);
-      gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+      gl::BindBufferBase(gl::UNIFORM_BUFFER, lights::LIGHTS_UBO_BINDING, light_ubo);
+      if let Some(ssbo) = attr_ssbo {
+        gl::BindBufferBase(gl::SHADER_STORAGE_BUFFER, attributes::ATTRIBUTE_SSBO_BINDING, ssbo);
+      }
+    }
+    let heatmap = attr_channel
+      .as_ref()
+      .filter(|_| show_heatmap)
+      .map(|channel| (channel.min, channel.max));
+    renderer.draw(&camera, render_width, render_height, heatmap);
+    post.draw(renderer.vao(), window.get_framebuffer_size(), &dof);
+    let gpu_ms = renderer.end_frame(render_width, render_height);
+    if let (Some(controller), Some(gpu_ms)) = (quality.as_mut(), gpu_ms) {
+      if let Some(ms) = controller.record(gpu_ms) {
+        controller.apply(&mut renderer, &mut post);
+        println!("Frames at {:.1} ms, switching to {}", ms, controller.label());
+      }
     }
 
     window.swap_buffers();
-    glfw.poll_events();
+
+    // Nothing changes on screen without input, so stop spinning the GPU
+    let idle = !animating && glfw.get_time() - last_input_time > IDLE_TIMEOUT;
+    if idle {
+      // Returns early as soon as an event arrives
+      glfw.wait_events_timeout(1.0 / IDLE_FPS);
+    } else if let Some(max_fps) = args.max_fps {
+      let deadline = current_frame as f64 + 1.0 / max_fps;
+      glfw.poll_events();
+      loop {
+        let now = glfw.get_time();
+        if now >= deadline {
+          break;
+        }
+        glfw.wait_events_timeout(deadline - now);
+      }
+    } else {
+      glfw.poll_events();
+    }
   }
   
-  // Cleanup
+  // Cleanup, releasing the GPU pool while the context is still alive
+  drop(upload);
+  drop(renderer);
+  drop(node_pool);
   unsafe {
-    oasis_node_pool_destroy(handle);
-    gl::DeleteBuffers(1, &node_ssbo);
+    gl::DeleteBuffers(1, &light_ubo);
+    if let Some(ssbo) = attr_ssbo {
+      gl::DeleteBuffers(1, &ssbo);
+    }
   }
 }
 
-fn process_events(window: &mut glfw::Window, events: &Receiver<(f64, glfw::WindowEvent)>) {
+// Returns true if any event arrived, which keeps the viewer out of idle mode
+fn process_events(window: &mut glfw::Window, events: &Receiver<(f64, glfw::WindowEvent)>) -> bool {
+  let mut had_events = false;
   for (_, event) in glfw::flush_messages(events) {
+    had_events = true;
     match event {
       glfw::WindowEvent::FramebufferSize(width, height) => {
         unsafe { gl::Viewport(0, 0, width, height) }
@@ -401,4 +615,5 @@ fn process_events(window: &mut glfw::Window, events: &Receiver<(f64, glfw::Windo
       _ => {}
     }
   }
+  had_events
 }
\ No newline at end of file
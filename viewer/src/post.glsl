@@ -0,0 +1,76 @@
+#version 460 core
+
+// Depth of field post pass over the raymarched color and hit distance
+
+// Largest blur radius in pixels
+#ifndef MAX_COC_RADIUS
+  #define MAX_COC_RADIUS 12.0
+#endif
+
+#ifndef DOF_SAMPLES
+  #define DOF_SAMPLES 48
+#endif
+
+#define GOLDEN_ANGLE 2.39996323
+
+layout(binding = 0) uniform sampler2D uColor;
+layout(binding = 1) uniform sampler2D uDepth; // Hit distance, 1e30 on miss
+
+uniform vec2 uViewport;    // Output size in pixels
+uniform vec2 uRenderScale; // Part of the targets the raymarch pass filled
+uniform uint uDofEnabled;
+uniform uint uAutoFocus;   // Focus on whatever is under the screen center
+uniform float uFocusDist;  // Manual focus distance in world units
+uniform float uAperture;   // Blur strength, 0 = pinhole
+
+out vec4 oColor;
+
+// Circle of confusion radius in pixels
+float CoC(float depth, float focus) {
+  float coc = uAperture * abs(depth - focus) / max(depth, 1e-4);
+  return clamp(coc, 0.0, 1.0) * MAX_COC_RADIUS;
+}
+
+void main() {
+  // Keep the bilinear footprint inside the rendered part of the targets
+  vec2 texel = 1.0 / vec2(textureSize(uColor, 0));
+  vec2 uv_max = uRenderScale - 0.5 * texel;
+  vec2 uv = min(gl_FragCoord.xy / uViewport * uRenderScale, uv_max);
+  vec3 center = texture(uColor, uv).rgb;
+
+  if (uDofEnabled == 0) {
+    oColor = vec4(center, 1.0);
+    return;
+  }
+
+  float focus = uAutoFocus != 0 ? texture(uDepth, 0.5 * uRenderScale).r : uFocusDist;
+  float center_depth = texture(uDepth, uv).r;
+  float center_coc = CoC(center_depth, focus);
+
+  // Blur radii stay in full resolution pixels at any render scale
+  texel *= uRenderScale;
+  vec3 sum = center;
+  float weight_sum = 1.0;
+
+  // Gather over a golden-angle spiral; a sample only contributes if its blur
+  // reaches this pixel. Samples behind this pixel can't blur more than it
+  // does, which keeps a sharp foreground from being covered by background.
+  for (int i = 1; i < DOF_SAMPLES; i++) {
+    float r = MAX_COC_RADIUS * sqrt(float(i) / float(DOF_SAMPLES));
+    float theta = float(i) * GOLDEN_ANGLE;
+    vec2 offset = vec2(cos(theta), sin(theta)) * r;
+
+    vec2 sample_uv = min(uv + offset * texel, uv_max);
+    float sample_depth = texture(uDepth, sample_uv).r;
+    float sample_coc = CoC(sample_depth, focus);
+    if (sample_depth > center_depth) {
+      sample_coc = min(sample_coc, center_coc);
+    }
+
+    float weight = smoothstep(r - 1.0, r, sample_coc);
+    sum += texture(uColor, sample_uv).rgb * weight;
+    weight_sum += weight;
+  }
+
+  oColor = vec4(sum / weight_sum, 1.0);
+}
@@ -0,0 +1,179 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use std::ptr;
+
+extern crate gl;
+use self::gl::types::*;
+
+use oasis_render::{compile_shader, link_program, uniform_location, FULLSCREEN_VERTEX_SHADER};
+
+const POST_SHADER_SOURCE: &str = include_str!("post.glsl");
+
+pub struct DofSettings {
+  pub enabled: bool,
+  // Focus on whatever is under the screen center instead of `focus_distance`
+  pub auto_focus: bool,
+  pub focus_distance: f32,
+  pub aperture: f32,
+}
+
+impl Default for DofSettings {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      auto_focus: true,
+      focus_distance: 1.0,
+      aperture: 0.5,
+    }
+  }
+}
+
+fn create_target_texture(internal_format: GLenum, format: GLenum, width: u32, height: u32) -> GLuint {
+  let mut texture: GLuint = 0;
+  unsafe {
+    gl::GenTextures(1, &mut texture);
+    gl::BindTexture(gl::TEXTURE_2D, texture);
+    gl::TexImage2D(
+      gl::TEXTURE_2D,
+      0,
+      internal_format as GLint,
+      width as GLsizei,
+      height as GLsizei,
+      0,
+      format,
+      gl::FLOAT,
+      ptr::null(),
+    );
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as GLint);
+    gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as GLint);
+    gl::BindTexture(gl::TEXTURE_2D, 0);
+  }
+  texture
+}
+
+// Offscreen target for the raymarch pass (color + hit distance) and the
+// post pass that resolves it to the default framebuffer.
+pub struct PostProcess {
+  fbo: GLuint,
+  color_tex: GLuint,
+  depth_tex: GLuint,
+  width: u32,
+  height: u32,
+  // Fraction of the target's width and height the raymarch pass fills,
+  // the post pass upscales that corner to the whole viewport
+  pub render_scale: f32,
+  program: GLuint,
+  u_viewport: GLint,
+  u_render_scale: GLint,
+  u_dof_enabled: GLint,
+  u_auto_focus: GLint,
+  u_focus_dist: GLint,
+  u_aperture: GLint,
+}
+
+impl PostProcess {
+  pub fn new(width: u32, height: u32) -> Self {
+    let vs = compile_shader(FULLSCREEN_VERTEX_SHADER, gl::VERTEX_SHADER);
+    let fs = compile_shader(POST_SHADER_SOURCE, gl::FRAGMENT_SHADER);
+    let program = link_program(vs, fs);
+
+    let color_tex = create_target_texture(gl::RGBA16F, gl::RGBA, width, height);
+    let depth_tex = create_target_texture(gl::R32F, gl::RED, width, height);
+
+    let mut fbo: GLuint = 0;
+    unsafe {
+      gl::GenFramebuffers(1, &mut fbo);
+      gl::BindFramebuffer(gl::FRAMEBUFFER, fbo);
+      gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, color_tex, 0);
+      gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT1, gl::TEXTURE_2D, depth_tex, 0);
+      let draw_buffers = [gl::COLOR_ATTACHMENT0, gl::COLOR_ATTACHMENT1];
+      gl::DrawBuffers(draw_buffers.len() as GLsizei, draw_buffers.as_ptr());
+
+      if gl::CheckFramebufferStatus(gl::FRAMEBUFFER) != gl::FRAMEBUFFER_COMPLETE {
+        panic!("ERROR::FRAMEBUFFER::POST_TARGET_INCOMPLETE");
+      }
+      gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+    }
+
+    Self {
+      fbo,
+      color_tex,
+      depth_tex,
+      width,
+      height,
+      render_scale: 1.0,
+      program,
+      u_viewport: uniform_location(program, "uViewport"),
+      u_render_scale: uniform_location(program, "uRenderScale"),
+      u_dof_enabled: uniform_location(program, "uDofEnabled"),
+      u_auto_focus: uniform_location(program, "uAutoFocus"),
+      u_focus_dist: uniform_location(program, "uFocusDist"),
+      u_aperture: uniform_location(program, "uAperture"),
+    }
+  }
+
+  // Size the raymarch pass renders at for the current render scale
+  pub fn render_size(&self) -> (u32, u32) {
+    let scale = |size: u32| ((size as f32 * self.render_scale).round() as u32).clamp(1, size);
+    (scale(self.width), scale(self.height))
+  }
+
+  // Redirects the raymarch pass into the offscreen target
+  pub fn bind_target(&self) {
+    let (width, height) = self.render_size();
+    unsafe {
+      gl::BindFramebuffer(gl::FRAMEBUFFER, self.fbo);
+      gl::Viewport(0, 0, width as GLsizei, height as GLsizei);
+    }
+  }
+
+  // Resolves the offscreen target into the default framebuffer
+  pub fn draw(&self, vao: GLuint, viewport: (i32, i32), dof: &DofSettings) {
+    unsafe {
+      gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+      gl::Viewport(0, 0, viewport.0, viewport.1);
+
+      gl::UseProgram(self.program);
+      gl::Uniform2f(self.u_viewport, viewport.0 as f32, viewport.1 as f32);
+      let (width, height) = self.render_size();
+      gl::Uniform2f(self.u_render_scale, width as f32 / self.width as f32, height as f32 / self.height as f32);
+      gl::Uniform1ui(self.u_dof_enabled, dof.enabled as GLuint);
+      gl::Uniform1ui(self.u_auto_focus, dof.auto_focus as GLuint);
+      gl::Uniform1f(self.u_focus_dist, dof.focus_distance);
+      gl::Uniform1f(self.u_aperture, dof.aperture);
+
+      gl::BindTextureUnit(0, self.color_tex);
+      gl::BindTextureUnit(1, self.depth_tex);
+
+      gl::BindVertexArray(vao);
+      gl::DrawArrays(gl::TRIANGLE_STRIP, 0, 4);
+    }
+  }
+}
+
+impl Drop for PostProcess {
+  fn drop(&mut self) {
+    unsafe {
+      gl::DeleteFramebuffers(1, &self.fbo);
+      gl::DeleteTextures(1, &self.color_tex);
+      gl::DeleteTextures(1, &self.depth_tex);
+      gl::DeleteProgram(self.program);
+    }
+  }
+}
@@ -0,0 +1,200 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use oasis_render::{Renderer, DEFAULT_MAX_DEPTH, TIMER_QUERIES};
+
+use crate::post::PostProcess;
+
+// One step of the quality ladder, from full quality down
+pub struct QualityLevel {
+  pub render_scale: f32,
+  pub max_depth: u32,
+  pub shadows: bool,
+}
+
+const LEVELS: [QualityLevel; 6] = [
+  QualityLevel { render_scale: 1.0, max_depth: DEFAULT_MAX_DEPTH, shadows: true },
+  QualityLevel { render_scale: 0.85, max_depth: DEFAULT_MAX_DEPTH, shadows: true },
+  QualityLevel { render_scale: 0.7, max_depth: DEFAULT_MAX_DEPTH - 1, shadows: true },
+  QualityLevel { render_scale: 0.7, max_depth: DEFAULT_MAX_DEPTH - 1, shadows: false },
+  QualityLevel { render_scale: 0.55, max_depth: DEFAULT_MAX_DEPTH - 2, shadows: false },
+  QualityLevel { render_scale: 0.4, max_depth: DEFAULT_MAX_DEPTH - 3, shadows: false },
+];
+
+// Weight of the newest frame in the smoothed GPU time
+const SMOOTHING: f32 = 0.1;
+// Frames over the target before dropping a level
+const DOWNGRADE_FRAMES: u32 = 10;
+// Frames under UPGRADE_HEADROOM * target before raising a level. Much longer
+// than a drop so a level that only just fits doesn't flip back and forth.
+const UPGRADE_FRAMES: u32 = 90;
+const UPGRADE_HEADROOM: f32 = 0.7;
+
+// Walks the quality ladder to keep the GPU time of a frame under a target.
+// GPU time rather than frame time, so vsync and the idle throttle don't
+// read as a slow frame. The renderer measures it, see `Renderer::end_frame`.
+pub struct QualityController {
+  target_ms: f32,
+  level: usize,
+  smoothed: Option<f32>,
+  over: u32,
+  under: u32,
+  // Results still to come from frames drawn at the previous level
+  discard: usize,
+}
+
+impl QualityController {
+  pub fn new(target_ms: f32) -> Self {
+    Self {
+      target_ms,
+      level: 0,
+      smoothed: None,
+      over: 0,
+      under: 0,
+      discard: 0,
+    }
+  }
+
+  pub fn level(&self) -> &QualityLevel {
+    &LEVELS[self.level]
+  }
+
+  // Applies the current level to the passes it scales
+  pub fn apply(&self, renderer: &mut Renderer, post: &mut PostProcess) {
+    let level = self.level();
+    renderer.max_depth = level.max_depth;
+    renderer.shadows = level.shadows;
+    post.render_scale = level.render_scale;
+  }
+
+  // Takes the GPU time of a frame in ms, returns the smoothed time when it
+  // moved the level, which then needs applying
+  pub fn record(&mut self, ms: f32) -> Option<f32> {
+    if self.discard > 0 {
+      self.discard -= 1;
+      return None;
+    }
+    let smoothed = match self.smoothed {
+      Some(previous) => previous + (ms - previous) * SMOOTHING,
+      None => ms,
+    };
+    self.smoothed = Some(smoothed);
+
+    if smoothed > self.target_ms {
+      self.over += 1;
+      self.under = 0;
+    } else if smoothed < self.target_ms * UPGRADE_HEADROOM {
+      self.under += 1;
+      self.over = 0;
+    } else {
+      self.over = 0;
+      self.under = 0;
+    }
+
+    let level = if self.over >= DOWNGRADE_FRAMES && self.level + 1 < LEVELS.len() {
+      self.level + 1
+    } else if self.under >= UPGRADE_FRAMES && self.level > 0 {
+      self.level - 1
+    } else {
+      return None;
+    };
+
+    // Frames still in flight were drawn at the old level, start measuring over
+    self.level = level;
+    self.smoothed = None;
+    self.over = 0;
+    self.under = 0;
+    self.discard = TIMER_QUERIES;
+    Some(smoothed)
+  }
+
+  // Short description for the window title
+  pub fn label(&self) -> String {
+    let level = self.level();
+    format!(
+      "quality {}/{} ({:.0}% scale, depth {}{})",
+      LEVELS.len() - self.level,
+      LEVELS.len(),
+      level.render_scale * 100.0,
+      level.max_depth,
+      if level.shadows { "" } else { ", no shadows" },
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TARGET: f32 = 10.0;
+
+  // Feeds `frames` identical frame times, returning how many moved the level
+  fn feed(controller: &mut QualityController, ms: f32, frames: usize) -> usize {
+    (0..frames).filter(|_| controller.record(ms).is_some()).count()
+  }
+
+  #[test]
+  fn slow_frames_drop_a_level() {
+    let mut controller = QualityController::new(TARGET);
+    assert_eq!(feed(&mut controller, 15.0, DOWNGRADE_FRAMES as usize - 1), 0);
+    assert_eq!(controller.record(15.0), Some(15.0));
+    assert_eq!(controller.level, 1);
+  }
+
+  #[test]
+  fn fast_frames_raise_a_level() {
+    let mut controller = QualityController::new(TARGET);
+    controller.level = 2;
+    let ms = TARGET * UPGRADE_HEADROOM - 1.0;
+    assert_eq!(feed(&mut controller, ms, UPGRADE_FRAMES as usize - 1), 0);
+    assert!(controller.record(ms).is_some());
+    assert_eq!(controller.level, 1);
+
+    // Frames between the headroom and the target hold the level
+    let mut controller = QualityController::new(TARGET);
+    controller.level = 2;
+    assert_eq!(feed(&mut controller, TARGET * 0.9, 1000), 0);
+    assert_eq!(controller.level, 2);
+  }
+
+  #[test]
+  fn results_after_a_change_are_discarded() {
+    let mut controller = QualityController::new(TARGET);
+    assert_eq!(feed(&mut controller, 15.0, DOWNGRADE_FRAMES as usize), 1);
+    assert_eq!(controller.discard, TIMER_QUERIES);
+
+    // Absurd times from frames still in flight don't count
+    assert_eq!(feed(&mut controller, 1000.0, TIMER_QUERIES), 0);
+    assert_eq!(controller.smoothed, None);
+    assert_eq!(controller.over, 0);
+
+    // Measuring restarts from the first result at the new level
+    controller.record(3.0);
+    assert_eq!(controller.smoothed, Some(3.0));
+  }
+
+  #[test]
+  fn level_is_clamped_to_the_ladder() {
+    let mut controller = QualityController::new(TARGET);
+    assert_eq!(feed(&mut controller, 1.0, 1000), 0);
+    assert_eq!(controller.level, 0);
+
+    feed(&mut controller, 100.0, 1000);
+    assert_eq!(controller.level, LEVELS.len() - 1);
+    assert_eq!(feed(&mut controller, 100.0, 1000), 0);
+    assert_eq!(controller.level, LEVELS.len() - 1);
+  }
+}
@@ -0,0 +1,303 @@
+/*
+ * Example Code for the Oasis Graphics Framework
+ * Copyright (c) 2025 REFUGE STUDIOS PTY LTD.
+ * Created by Aidan Sanders <aidan.sanders@refugestudios.com.au>
+ *
+ * This example code is licensed under the MIT License.
+ * You are free to use, modify, and distribute this code for any purpose,
+ * including commercial applications, as long as this notice is retained.
+ *
+ * THE OASIS API ITSELF IS PROPRIETARY AND NOT COVERED UNDER THIS LICENSE.
+ * These examples are intended to demonstrate usage of the Oasis API,
+ * and require a licensed copy of Oasis to function.
+ *
+ * For licensing Oasis itself, please contact: aidan.sanders@refugestudios.com.au
+ */
+
+use oasis_render::{MAX_FOV, MIN_FOV};
+
+use crate::bookmarks::{Bookmark, Bookmarks};
+
+// Step size of the frame step keys
+pub const FRAME_RATE: f32 = 30.0;
+
+// Timeline seconds per second while scrubbing with Shift+Left/Right
+pub const SCRUB_RATE: f32 = 4.0;
+
+// Seconds the camera path spends between two bookmarks
+const SEGMENT_SECONDS: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopMode {
+  Once,
+  Loop,
+  PingPong,
+}
+
+impl LoopMode {
+  pub fn next(self) -> Self {
+    match self {
+      LoopMode::Once => LoopMode::Loop,
+      LoopMode::Loop => LoopMode::PingPong,
+      LoopMode::PingPong => LoopMode::Once,
+    }
+  }
+
+  pub fn name(self) -> &'static str {
+    match self {
+      LoopMode::Once => "once",
+      LoopMode::Loop => "loop",
+      LoopMode::PingPong => "ping-pong",
+    }
+  }
+}
+
+// Transport shared by everything animated: the camera path follows it and
+// the shader sees it as uTime. `duration` is the length of what is being
+// played, None when there is nothing with an end and time just runs.
+pub struct Timeline {
+  pub time: f32,
+  pub playing: bool,
+  pub loop_mode: LoopMode,
+  // -1 while a ping-pong is playing backwards
+  direction: f32,
+}
+
+impl Default for Timeline {
+  fn default() -> Self {
+    Self {
+      time: 0.0,
+      playing: false,
+      loop_mode: LoopMode::Loop,
+      direction: 1.0,
+    }
+  }
+}
+
+impl Timeline {
+  // Starts or pauses playback. Playing a finished run starts it over, a
+  // paused ping-pong carries on in the direction it was going.
+  pub fn toggle(&mut self, duration: Option<f32>) {
+    self.playing = !self.playing;
+    if let (true, LoopMode::Once, Some(duration)) = (self.playing, self.loop_mode, duration) {
+      if self.time >= duration {
+        self.rewind();
+      }
+    }
+  }
+
+  pub fn rewind(&mut self) {
+    self.time = 0.0;
+    self.direction = 1.0;
+  }
+
+  pub fn advance(&mut self, delta_time: f32, duration: Option<f32>) {
+    if !self.playing {
+      return;
+    }
+    self.time += delta_time * self.direction;
+    let Some(duration) = duration else {
+      return;
+    };
+    match self.loop_mode {
+      LoopMode::Once if self.time >= duration => {
+        self.time = duration;
+        self.playing = false;
+      }
+      LoopMode::Once => {}
+      LoopMode::Loop => self.time = self.time.rem_euclid(duration.max(f32::EPSILON)),
+      LoopMode::PingPong => {
+        if self.time > duration {
+          self.time = 2.0 * duration - self.time;
+          self.direction = -1.0;
+        } else if self.time < 0.0 {
+          self.time = -self.time;
+          self.direction = 1.0;
+        }
+        self.time = self.time.clamp(0.0, duration);
+      }
+    }
+  }
+
+  // Moves by whole frames and pauses, for inspecting one moment
+  pub fn step_frames(&mut self, frames: i32, duration: Option<f32>) {
+    self.playing = false;
+    self.seek(self.time + frames as f32 / FRAME_RATE, duration);
+  }
+
+  pub fn seek(&mut self, time: f32, duration: Option<f32>) {
+    self.time = time.clamp(0.0, duration.unwrap_or(f32::MAX));
+  }
+
+  // Short description for the window title
+  pub fn label(&self, duration: Option<f32>) -> String {
+    let state = if self.playing { "playing" } else { "paused" };
+    match duration {
+      Some(duration) => format!("{} {:.1}/{:.1} s ({})", state, self.time, duration, self.loop_mode.name()),
+      None => format!("{} {:.1} s", state, self.time),
+    }
+  }
+}
+
+// Camera path through the saved bookmarks in slot order, a Catmull-Rom spline
+// so the camera doesn't stop at each one
+pub struct CameraPath {
+  keys: Vec<Bookmark>,
+}
+
+impl CameraPath {
+  // Needs at least two bookmarks to go anywhere
+  pub fn from_bookmarks(bookmarks: &Bookmarks) -> Option<Self> {
+    let mut keys: Vec<Bookmark> = bookmarks.iter().map(|(_, bookmark)| bookmark).collect();
+    if keys.len() < 2 {
+      return None;
+    }
+    // Turn the short way between keys instead of unwinding accumulated yaw
+    let mut previous = keys[0].yaw;
+    for key in keys.iter_mut().skip(1) {
+      let turn = (key.yaw - previous + 180.0).rem_euclid(360.0) - 180.0;
+      key.yaw = previous + turn;
+      previous = key.yaw;
+    }
+    Some(Self { keys })
+  }
+
+  pub fn duration(&self) -> f32 {
+    (self.keys.len() - 1) as f32 * SEGMENT_SECONDS
+  }
+
+  pub fn sample(&self, time: f32) -> Bookmark {
+    let last = self.keys.len() - 1;
+    let t = (time / SEGMENT_SECONDS).clamp(0.0, last as f32);
+    let segment = (t as usize).min(last - 1);
+    let s = t - segment as f32;
+
+    // Ends repeat the first and last key
+    let key = |i: isize| self.keys[i.clamp(0, last as isize) as usize];
+    let i = segment as isize;
+    let (k0, k1, k2, k3) = (key(i - 1), key(i), key(i + 1), key(i + 2));
+
+    let spline = |p0: f32, p1: f32, p2: f32, p3: f32| {
+      0.5 * (2.0 * p1
+        + (p2 - p0) * s
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * s * s
+        + (3.0 * (p1 - p2) + p3 - p0) * s * s * s)
+    };
+    let position = |axis: usize| spline(k0.position[axis], k1.position[axis], k2.position[axis], k3.position[axis]);
+    Bookmark {
+      position: [position(0), position(1), position(2)],
+      yaw: spline(k0.yaw, k1.yaw, k2.yaw, k3.yaw),
+      pitch: spline(k0.pitch, k1.pitch, k2.pitch, k3.pitch).clamp(-89.0, 89.0),
+      fov: spline(k0.fov, k1.fov, k2.fov, k3.fov).clamp(MIN_FOV, MAX_FOV),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const DURATION: Option<f32> = Some(2.0);
+
+  fn playing(loop_mode: LoopMode) -> Timeline {
+    Timeline {
+      playing: true,
+      loop_mode,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn once_stops_at_the_end_and_restarts() {
+    let mut timeline = playing(LoopMode::Once);
+    timeline.advance(1.5, DURATION);
+    assert_eq!((timeline.time, timeline.playing), (1.5, true));
+    timeline.advance(1.0, DURATION);
+    assert_eq!((timeline.time, timeline.playing), (2.0, false));
+
+    timeline.toggle(DURATION);
+    assert_eq!((timeline.time, timeline.playing), (0.0, true));
+  }
+
+  #[test]
+  fn loop_wraps_around() {
+    let mut timeline = playing(LoopMode::Loop);
+    timeline.advance(2.5, DURATION);
+    assert_eq!(timeline.time, 0.5);
+    timeline.advance(3.5, DURATION);
+    assert_eq!(timeline.time, 0.0);
+    assert!(timeline.playing);
+  }
+
+  #[test]
+  fn ping_pong_bounces_off_both_ends() {
+    let mut timeline = playing(LoopMode::PingPong);
+    timeline.advance(2.5, DURATION);
+    assert_eq!((timeline.time, timeline.direction), (1.5, -1.0));
+    timeline.advance(1.0, DURATION);
+    assert_eq!((timeline.time, timeline.direction), (0.5, -1.0));
+
+    // Pausing and resuming keeps going backwards
+    timeline.toggle(DURATION);
+    timeline.advance(1.0, DURATION);
+    assert_eq!(timeline.time, 0.5);
+    timeline.toggle(DURATION);
+    assert_eq!(timeline.direction, -1.0);
+
+    timeline.advance(1.0, DURATION);
+    assert_eq!((timeline.time, timeline.direction), (0.5, 1.0));
+  }
+
+  #[test]
+  fn time_runs_without_a_duration() {
+    for loop_mode in [LoopMode::Once, LoopMode::Loop, LoopMode::PingPong] {
+      let mut timeline = playing(loop_mode);
+      timeline.advance(100.0, None);
+      assert_eq!((timeline.time, timeline.playing), (100.0, true));
+    }
+  }
+
+  fn key(x: f32, yaw: f32) -> Bookmark {
+    Bookmark {
+      position: [x, 1.0, -x],
+      yaw,
+      pitch: 10.0,
+      fov: 60.0,
+    }
+  }
+
+  fn assert_near(a: Bookmark, b: Bookmark) {
+    let near = |a: f32, b: f32| (a - b).abs() < 1e-4;
+    assert!(
+      (0..3).all(|k| near(a.position[k], b.position[k]))
+        && near(a.yaw, b.yaw)
+        && near(a.pitch, b.pitch)
+        && near(a.fov, b.fov),
+      "{:?} != {:?}",
+      a,
+      b
+    );
+  }
+
+  #[test]
+  fn path_passes_through_its_keys() {
+    let keys = vec![key(0.0, 0.0), key(4.0, 90.0), key(2.0, 45.0)];
+    let path = CameraPath { keys: keys.clone() };
+    assert_eq!(path.duration(), 2.0 * SEGMENT_SECONDS);
+
+    assert_near(path.sample(0.0), keys[0]);
+    assert_near(path.sample(SEGMENT_SECONDS), keys[1]);
+    assert_near(path.sample(path.duration()), keys[2]);
+    // Times outside the path hold the end keys
+    assert_near(path.sample(-1.0), keys[0]);
+    assert_near(path.sample(path.duration() + 1.0), keys[2]);
+  }
+
+  #[test]
+  fn two_key_paths_reach_both_ends() {
+    let keys = vec![key(0.0, 0.0), key(4.0, 90.0)];
+    let path = CameraPath { keys: keys.clone() };
+    assert_near(path.sample(0.0), keys[0]);
+    assert_near(path.sample(path.duration()), keys[1]);
+  }
+}